@@ -0,0 +1,207 @@
+use chrono::NaiveDate;
+use scheduling::{AccrualDates, Daycounts, Schedule, SchedulePeriod};
+
+use crate::CashflowError;
+
+/// Accrued interest on a fixed coupon bond, quoted per 100 of face value like its
+/// price.
+///
+/// Accrual runs from the start of the coupon period containing the settlement date.
+/// Settling in the ex-coupon period, i.e. on or after the period's record date, the
+/// buyer does not receive the next coupon and the accrued is negative: minus the
+/// interest from settlement to the end of the period. Record dates come only from the
+/// schedule, see
+/// [`ScheduleBuilder::ex_coupon`](scheduling::ScheduleBuilder::ex_coupon).
+pub struct AccruedInterest<'s, 'a> {
+    schedule: &'s Schedule<'a>,
+    daycount: Daycounts<'a>,
+    coupon: f64,
+    accrual: AccrualDates,
+}
+
+impl<'s, 'a> AccruedInterest<'s, 'a> {
+    pub fn new(
+        schedule: &'s Schedule<'a>,
+        daycount: Daycounts<'a>,
+        coupon: f64,
+    ) -> Self {
+        Self {
+            schedule,
+            daycount,
+            coupon,
+            accrual: AccrualDates::default(),
+        }
+    }
+
+    /// Set which period dates accrual is measured between; bonds typically accrue on
+    /// the unadjusted coupon dates, which is the default.
+    pub fn accrual_dates(mut self, accrual: AccrualDates) -> Self {
+        self.accrual = accrual;
+        self
+    }
+
+    /// Compute the accrued interest per 100 face for settlement on ``settlement``.
+    pub fn accrued(&self, settlement: NaiveDate) -> Result<f64, CashflowError> {
+        let period = self.period(settlement)?;
+        let accrued = |dt| {
+            100.0
+                * self.coupon
+                * self.daycount.accrued_year_fraction(
+                    period,
+                    self.schedule,
+                    self.accrual,
+                    dt,
+                )
+        };
+        let end = period.accrual_dates(self.accrual).1;
+        if period.is_ex_coupon(settlement) {
+            Ok(accrued(settlement) - accrued(end))
+        } else {
+            Ok(accrued(settlement))
+        }
+    }
+
+    /// Convert a clean price to the dirty price for settlement on ``settlement``.
+    pub fn dirty_price(
+        &self,
+        clean: f64,
+        settlement: NaiveDate,
+    ) -> Result<f64, CashflowError> {
+        Ok(clean + self.accrued(settlement)?)
+    }
+
+    /// Convert a dirty price to the clean price for settlement on ``settlement``.
+    pub fn clean_price(
+        &self,
+        dirty: f64,
+        settlement: NaiveDate,
+    ) -> Result<f64, CashflowError> {
+        Ok(dirty - self.accrued(settlement)?)
+    }
+
+    fn period(&self, dt: NaiveDate) -> Result<&'s SchedulePeriod, CashflowError> {
+        self.schedule
+            .find_period(dt, self.accrual)
+            .map(|(_, p)| p)
+            .ok_or_else(|| {
+                CashflowError::InvalidPeriod(format!(
+                    "settlement {dt} is outside the schedule"
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bizdate::{BusdayConvention, BusinessCalendar};
+    use chrono::NaiveDate;
+    use scheduling::{Daycounts, Period, Schedule, ScheduleBuilder};
+
+    use super::AccruedInterest;
+    use crate::CashflowError;
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+
+    fn bond_builder<'a>(
+        effective: &str,
+        termination: &str,
+        period: Period<'a>,
+        cal: &'a BusinessCalendar,
+    ) -> ScheduleBuilder<'a> {
+        Schedule::builder()
+            .effective(ymd(effective))
+            .termination(ymd(termination))
+            .period(period)
+            .payment_calendar(cal)
+            .payment_convention(BusdayConvention::Following)
+    }
+
+    fn bond<'a>(
+        effective: &str,
+        termination: &str,
+        period: Period<'a>,
+        cal: &'a BusinessCalendar,
+    ) -> Schedule<'a> {
+        bond_builder(effective, termination, period, cal)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ust() {
+        // * 4% semi-annual note, Act/Act ICMA; 2026-05-15 to 2026-08-20 is 97 of 184
+        // * days
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = bond("2025-11-15", "2030-11-15", Period::Months(6), &cal);
+        let ai = AccruedInterest::new(&sch, Daycounts::ActActIcma, 0.04);
+        assert_close(ai.accrued(ymd("2026-08-20")).unwrap(), 2.0 * 97.0 / 184.0);
+        assert_eq!(ai.accrued(ymd("2026-05-15")).unwrap(), 0.0);
+
+        // * a coupon date on a weekend still accrues from the unadjusted date
+        // * (2027-05-15 is a Saturday)
+        assert_close(ai.accrued(ymd("2027-05-17")).unwrap(), 2.0 * 2.0 / 184.0);
+    }
+
+    #[test]
+    fn test_gilt() {
+        // * 4.25% gilt paying 7 Jun/Dec, ex-dividend 7 business days before payment:
+        // * 2026-11-26 for the 2026-12-07 coupon; 2026-06-07 to 2026-12-07 is 183 days
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = bond_builder("2025-12-07", "2027-12-07", Period::Months(6), &cal)
+            .ex_coupon(Period::BusDays(7, &cal))
+            .build()
+            .unwrap();
+        let ai = AccruedInterest::new(&sch, Daycounts::ActActIcma, 0.0425);
+
+        assert_close(
+            ai.accrued(ymd("2026-11-25")).unwrap(),
+            2.125 * 171.0 / 183.0,
+        );
+        assert_close(
+            ai.accrued(ymd("2026-11-26")).unwrap(),
+            -2.125 * 11.0 / 183.0,
+        );
+        assert_close(ai.accrued(ymd("2026-11-30")).unwrap(), -2.125 * 7.0 / 183.0);
+        assert_eq!(ai.accrued(ymd("2026-12-07")).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_bund() {
+        // * 2.5% annual Bund paying 15 Feb; 2026-02-15 to 2026-06-10 is 115 of 365 days
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = bond("2025-02-15", "2035-02-15", Period::Years(1), &cal);
+        let ai = AccruedInterest::new(&sch, Daycounts::ActActIcma, 0.025);
+        assert_close(ai.accrued(ymd("2026-06-10")).unwrap(), 2.5 * 115.0 / 365.0);
+
+        // * the 2028 period spans 29 Feb and is 366 days long
+        assert_close(ai.accrued(ymd("2028-03-01")).unwrap(), 2.5 * 15.0 / 366.0);
+    }
+
+    #[test]
+    fn test_clean_dirty() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = bond("2025-11-15", "2030-11-15", Period::Months(6), &cal);
+        let ai = AccruedInterest::new(&sch, Daycounts::ActActIcma, 0.04);
+        let settlement = ymd("2026-08-20");
+        let dirty = ai.dirty_price(99.5, settlement).unwrap();
+        assert_close(dirty, 99.5 + 2.0 * 97.0 / 184.0);
+        assert_close(ai.clean_price(dirty, settlement).unwrap(), 99.5);
+    }
+
+    #[test]
+    fn test_outside_schedule() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = bond("2025-11-15", "2030-11-15", Period::Months(6), &cal);
+        let ai = AccruedInterest::new(&sch, Daycounts::ActActIcma, 0.04);
+        for dt in ["2025-11-14", "2030-11-15"] {
+            let rslt = ai.accrued(ymd(dt));
+            assert!(matches!(rslt, Err(CashflowError::InvalidPeriod(_))), "{dt}");
+        }
+    }
+}
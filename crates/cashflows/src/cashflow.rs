@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+
+/// An interest cashflow accrued over a single period of a leg.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cashflow {
+    pub(crate) accrual_start: NaiveDate,
+    pub(crate) accrual_end: NaiveDate,
+    pub(crate) accrual_factor: f64,
+    pub(crate) pay: NaiveDate,
+    pub(crate) notional: f64,
+    pub(crate) rate: f64,
+    pub(crate) amount: f64,
+}
+
+impl Cashflow {
+    /// Get the date accrual starts on.
+    pub fn accrual_start(&self) -> NaiveDate {
+        self.accrual_start
+    }
+
+    /// Get the date accrual ends on.
+    pub fn accrual_end(&self) -> NaiveDate {
+        self.accrual_end
+    }
+
+    /// Get the year fraction the rate accrues over.
+    pub fn accrual_factor(&self) -> f64 {
+        self.accrual_factor
+    }
+
+    /// Get the payment date.
+    pub fn pay(&self) -> NaiveDate {
+        self.pay
+    }
+
+    /// Get the notional the rate accrues on.
+    pub fn notional(&self) -> f64 {
+        self.notional
+    }
+
+    /// Get the annual rate of the coupon.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Get the amount paid, i.e. notional x rate x accrual factor.
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+}
@@ -0,0 +1,322 @@
+use chrono::{Days, NaiveDate};
+use scheduling::{
+    Daycounts, ObservationSchedule, OvernightObservation, SchedulePeriod,
+};
+
+use crate::{CashflowError, FixingSource, RateProjection};
+
+/// How daily overnight rates are combined into the rate for a period.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RateAveraging {
+    /// Daily compounded, e.g. SOFR or SONIA compounded in arrears
+    #[default]
+    Compounded,
+    /// Weighted arithmetic average of the daily rates
+    Simple,
+}
+
+/// Calculator of the rate on an overnight-indexed period from its daily fixings.
+///
+/// Each daily rate accrues over its weight with the day count convention, so under
+/// Act/360 the compounded rate is the familiar
+/// $(\prod_i (1 + r_i n_i / 360) - 1) \times 360 / N$.
+pub struct OvernightRate<'a> {
+    observation: OvernightObservation<'a>,
+    daycount: Daycounts<'a>,
+    averaging: RateAveraging,
+}
+
+impl<'a> OvernightRate<'a> {
+    pub fn new(observation: OvernightObservation<'a>, daycount: Daycounts<'a>) -> Self {
+        Self {
+            observation,
+            daycount,
+            averaging: RateAveraging::default(),
+        }
+    }
+
+    pub fn averaging(mut self, averaging: RateAveraging) -> Self {
+        self.averaging = averaging;
+        self
+    }
+
+    /// Compute the rate over ``period`` from the daily ``fixings``.
+    ///
+    /// Rates missing from ``fixings``, i.e. those not yet published in a partially
+    /// fixed period, are taken from ``projection``; without one they are an error.
+    pub fn rate<F: FixingSource + ?Sized>(
+        &self,
+        period: &SchedulePeriod,
+        fixings: &F,
+        projection: Option<&dyn RateProjection>,
+    ) -> Result<f64, CashflowError> {
+        let obs = self.observe(period)?;
+
+        let mut compounded = 1.0;
+        let mut averaged = 0.0;
+        for d in obs.dates() {
+            let dt = d.rate_date();
+            let rate = fixings
+                .fixing(dt)
+                .or_else(|| projection.map(|p| p.project(dt)))
+                .ok_or(CashflowError::MissingFixing(dt))?;
+            let yf = self
+                .daycount
+                .try_year_fraction(d.date(), day_end(d.date(), d.weight()))?;
+            compounded *= 1.0 + rate * yf;
+            averaged += rate * yf;
+        }
+
+        let (start, end) = bounds(&obs);
+        let yf = self.daycount.try_year_fraction(start, end)?;
+        Ok(match self.averaging {
+            RateAveraging::Compounded => (compounded - 1.0) / yf,
+            RateAveraging::Simple => averaged / yf,
+        })
+    }
+
+    /// Compute the compounded rate over ``period`` as the ratio of a published index,
+    /// e.g. the SOFR Index, at the bounds of the observation period.
+    ///
+    /// The index is read on the first observation date and the business day after
+    /// the last one, so it is only consistent with in arrears or observation shift
+    /// observation; a lookback or lockout, which observe rates on other dates, is an
+    /// error.
+    pub fn index_ratio<F: FixingSource + ?Sized>(
+        &self,
+        period: &SchedulePeriod,
+        index: &F,
+    ) -> Result<f64, CashflowError> {
+        let obs = self.observe(period)?;
+        if obs.dates().iter().any(|d| d.rate_date() != d.date()) {
+            return Err(CashflowError::InvalidPeriod(
+                "an index ratio cannot apply a lookback or lockout".to_string(),
+            ));
+        }
+        let (start, end) = bounds(&obs);
+        let fixing = |dt| index.fixing(dt).ok_or(CashflowError::MissingFixing(dt));
+        let (first, last) = (fixing(start)?, fixing(end)?);
+        let yf = self.daycount.try_year_fraction(start, end)?;
+        Ok((last / first - 1.0) / yf)
+    }
+
+    fn observe(
+        &self,
+        period: &SchedulePeriod,
+    ) -> Result<ObservationSchedule, CashflowError> {
+        let obs = self.observation.generate(period);
+        if obs.dates().is_empty() {
+            return Err(CashflowError::InvalidPeriod(format!(
+                "no business days between {} and {}",
+                period.start_adj(),
+                period.end_adj()
+            )));
+        }
+        Ok(obs)
+    }
+}
+
+/// Get the end of the days a rate observed on ``dt`` applies for.
+fn day_end(dt: NaiveDate, weight: i64) -> NaiveDate {
+    dt + Days::new(weight as u64)
+}
+
+/// Get the start and end of the observation period.
+fn bounds(obs: &ObservationSchedule) -> (NaiveDate, NaiveDate) {
+    let dates = obs.dates();
+    let last = dates[dates.len() - 1];
+    (dates[0].date(), day_end(last.date(), last.weight()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use bizdate::{BusdayConvention, BusinessCalendar};
+    use chrono::{Days, NaiveDate};
+    use scheduling::{
+        Daycounts, ObservationMethod, OvernightObservation, SchedulePeriod,
+        SchedulingError,
+    };
+
+    use super::{OvernightRate, RateAveraging};
+    use crate::{CashflowError, RateProjection};
+
+    // * synthetic daily fixings, in decimal, for 2026-03-02 to 2026-03-31; the NY Fed
+    // * publication rounds the index to 8 and averages to 5 decimal places (in percent)
+    const RATES: [f64; 22] = [
+        0.0365, 0.0364, 0.0366, 0.0365, 0.0363, 0.0362, 0.0362, 0.0361, 0.0364, 0.0365,
+        0.0365, 0.0366, 0.0367, 0.0369, 0.0372, 0.0368, 0.0366, 0.0365, 0.0364, 0.0364,
+        0.0363, 0.0375,
+    ];
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn get_cal() -> BusinessCalendar {
+        BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100")
+    }
+
+    fn assert_close(a: f64, b: f64, tol: f64) {
+        assert!((a - b).abs() < tol, "{a} != {b}");
+    }
+
+    /// Get the daily fixings keyed by business day, and the index they imply,
+    /// starting at 1 and rounded to 8 decimal places as published.
+    fn fixings(
+        cal: &BusinessCalendar,
+    ) -> (BTreeMap<NaiveDate, f64>, BTreeMap<NaiveDate, f64>) {
+        let (mut rates, mut index) = (BTreeMap::new(), BTreeMap::new());
+        let mut dt = ymd("2026-03-02");
+        let mut value = 1.0;
+        for rate in RATES {
+            let next = cal.add_busdays(dt, 1, BusdayConvention::Following);
+            index.insert(dt, (value * 1e8_f64).round() / 1e8);
+            rates.insert(dt, rate);
+            value *= 1.0 + rate * (next - dt).num_days() as f64 / 360.0;
+            dt = next;
+        }
+        index.insert(dt, (value * 1e8_f64).round() / 1e8);
+        (rates, index)
+    }
+
+    fn period(start: &str, end: &str) -> SchedulePeriod {
+        let (start, end) = (ymd(start), ymd(end));
+        SchedulePeriod::new(start, end, start, end)
+    }
+
+    #[test]
+    fn test_compounded() {
+        let cal = get_cal();
+        let (rates, _) = fixings(&cal);
+        let calc =
+            OvernightRate::new(OvernightObservation::new(&cal), Daycounts::Act360);
+
+        // * one week: weights 1, 1, 1, 1, 3
+        let rslt = calc.rate(&period("2026-03-02", "2026-03-09"), &rates, None);
+        let factor: f64 = [(0.0365, 1.0), (0.0364, 1.0), (0.0366, 1.0), (0.0365, 1.0)]
+            .iter()
+            .chain(&[(0.0363, 3.0)])
+            .map(|(r, n)| 1.0 + r * n / 360.0)
+            .product();
+        assert_close(rslt.unwrap(), (factor - 1.0) * 360.0 / 7.0, 1e-14);
+    }
+
+    #[test]
+    fn test_simple_average() {
+        let cal = get_cal();
+        let (rates, _) = fixings(&cal);
+        let calc =
+            OvernightRate::new(OvernightObservation::new(&cal), Daycounts::Act360)
+                .averaging(RateAveraging::Simple);
+        let rslt = calc.rate(&period("2026-03-02", "2026-03-09"), &rates, None);
+        let expected = (0.0365 + 0.0364 + 0.0366 + 0.0365 + 0.0363 * 3.0) / 7.0;
+        assert_close(rslt.unwrap(), expected, 1e-14);
+    }
+
+    #[test]
+    fn test_index_ratio() {
+        // * the index ratio matches daily compounding to the index's rounding
+        let cal = get_cal();
+        let (rates, index) = fixings(&cal);
+        for method in [
+            ObservationMethod::InArrears,
+            ObservationMethod::ObservationShift(2),
+        ] {
+            let obs = OvernightObservation::new(&cal).method(method);
+            let calc = OvernightRate::new(obs, Daycounts::Act360);
+            let p = period("2026-03-06", "2026-03-27");
+            let compounded = calc.rate(&p, &rates, None).unwrap();
+            let ratio = calc.index_ratio(&p, &index).unwrap();
+            assert_close(compounded, ratio, 5e-7);
+        }
+
+        let calc =
+            OvernightRate::new(OvernightObservation::new(&cal), Daycounts::Act360);
+        let rslt = calc.index_ratio(&period("2026-03-27", "2026-04-06"), &index);
+        assert_eq!(rslt, Err(CashflowError::MissingFixing(ymd("2026-04-06"))));
+
+        // * the index cannot reflect rates observed on other dates
+        let p = period("2026-03-06", "2026-03-27");
+        for obs in [
+            OvernightObservation::new(&cal).method(ObservationMethod::Lookback(2)),
+            OvernightObservation::new(&cal).lockout(2),
+        ] {
+            let calc = OvernightRate::new(obs, Daycounts::Act360);
+            let rslt = calc.index_ratio(&p, &index);
+            assert!(matches!(rslt, Err(CashflowError::InvalidPeriod(_))));
+        }
+    }
+
+    #[test]
+    fn test_partially_fixed() {
+        let cal = get_cal();
+        let (rates, _) = fixings(&cal);
+        let known: BTreeMap<_, _> = rates
+            .range(..ymd("2026-03-05"))
+            .map(|(k, v)| (*k, *v))
+            .collect();
+        let calc =
+            OvernightRate::new(OvernightObservation::new(&cal), Daycounts::Act360);
+        let p = period("2026-03-02", "2026-03-09");
+
+        let rslt = calc.rate(&p, &known, None);
+        assert_eq!(rslt, Err(CashflowError::MissingFixing(ymd("2026-03-05"))));
+
+        // * projecting the remaining fixings exactly recovers the fully fixed rate
+        let projection = |dt| rates[&dt];
+        let projected = calc.rate(&p, &known, Some(&projection as &dyn RateProjection));
+        let fixed = calc.rate(&p, &rates, None);
+        assert_close(projected.unwrap(), fixed.unwrap(), 1e-15);
+    }
+
+    #[test]
+    fn test_daycount() {
+        // * each daily rate accrues, and the result annualises, on the convention
+        let cal = get_cal();
+        let (rates, _) = fixings(&cal);
+        let calc =
+            OvernightRate::new(OvernightObservation::new(&cal), Daycounts::Act365F);
+        let flat = BTreeMap::from_iter(rates.keys().map(|&k| (k, 0.05)));
+        let factor: f64 = [1.0, 1.0, 1.0, 1.0, 3.0]
+            .iter()
+            .map(|n| 1.0 + 0.05 * n / 365.0)
+            .product();
+        let rslt = calc.rate(&period("2026-03-02", "2026-03-09"), &flat, None);
+        assert_close(rslt.unwrap(), (factor - 1.0) * 365.0 / 7.0, 1e-14);
+
+        // * a day count that needs a schedule is an error, not a panic
+        let (_, index) = fixings(&cal);
+        let calc =
+            OvernightRate::new(OvernightObservation::new(&cal), Daycounts::ActActIcma);
+        let p = period("2026-03-02", "2026-03-09");
+        for rslt in [calc.rate(&p, &flat, None), calc.index_ratio(&p, &index)] {
+            assert!(matches!(
+                rslt,
+                Err(CashflowError::Scheduling(SchedulingError::InvalidDaycount(
+                    _
+                )))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_empty_period() {
+        let cal = get_cal();
+        let (rates, _) = fixings(&cal);
+        let calc =
+            OvernightRate::new(OvernightObservation::new(&cal), Daycounts::Act360);
+        let start = ymd("2026-03-07");
+        let p = SchedulePeriod::new(
+            start,
+            start + Days::new(1),
+            start,
+            start + Days::new(1),
+        );
+        assert!(matches!(
+            calc.rate(&p, &rates, None),
+            Err(CashflowError::InvalidPeriod(_))
+        ));
+    }
+}
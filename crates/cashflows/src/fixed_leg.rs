@@ -0,0 +1,234 @@
+use chrono::NaiveDate;
+use scheduling::{AccrualDates, Daycounts, Schedule};
+
+use crate::{Cashflow, CashflowError};
+
+/// Coupon rate of a fixed leg.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CouponRate {
+    Constant(f64),
+    /// Rates effective for periods starting on or after each date, in ascending date
+    /// order
+    StepUp(Vec<(NaiveDate, f64)>),
+}
+
+impl CouponRate {
+    /// Get the rate for a period starting on ``dt``, if any.
+    pub fn rate(&self, dt: NaiveDate) -> Option<f64> {
+        match self {
+            Self::Constant(rate) => Some(*rate),
+            Self::StepUp(steps) => steps
+                .iter()
+                .take_while(|(start, _)| *start <= dt)
+                .last()
+                .map(|(_, rate)| *rate),
+        }
+    }
+}
+
+/// Leg paying a fixed rate on every period of a [`Schedule`].
+///
+/// Without an explicit notional each period accrues on the notional outstanding in
+/// the amortized schedule, and an unamortized schedule is an error. Accrual is
+/// measured between the unadjusted period dates by default, as for most bonds; swaps
+/// typically accrue on the adjusted dates.
+pub struct FixedLeg<'s, 'a> {
+    schedule: &'s Schedule<'a>,
+    rate: CouponRate,
+    daycount: Daycounts<'a>,
+    notional: Option<f64>,
+    accrual: AccrualDates,
+}
+
+impl<'s, 'a> FixedLeg<'s, 'a> {
+    pub fn new(
+        schedule: &'s Schedule<'a>,
+        rate: CouponRate,
+        daycount: Daycounts<'a>,
+    ) -> Self {
+        Self {
+            schedule,
+            rate,
+            daycount,
+            notional: None,
+            accrual: AccrualDates::default(),
+        }
+    }
+
+    /// Set a constant notional in place of the schedule's amortization profile.
+    pub fn notional(mut self, notional: f64) -> Self {
+        self.notional = Some(notional);
+        self
+    }
+
+    /// Set which period dates accrual is measured between.
+    pub fn accrual_dates(mut self, accrual: AccrualDates) -> Self {
+        self.accrual = accrual;
+        self
+    }
+
+    /// Generate the cashflow of every period.
+    pub fn cashflows(&self) -> Result<Vec<Cashflow>, CashflowError> {
+        if let CouponRate::StepUp(steps) = &self.rate
+            && steps.windows(2).any(|w| w[0].0 >= w[1].0)
+        {
+            return Err(CashflowError::InvalidLeg(
+                "step-up dates must be ascending".to_string(),
+            ));
+        }
+
+        let factors = self
+            .daycount
+            .schedule_year_fractions(self.schedule, self.accrual);
+        self.schedule
+            .iter()
+            .zip(factors)
+            .map(|(p, accrual_factor)| {
+                let rate = self.rate.rate(p.start()).ok_or_else(|| {
+                    CashflowError::InvalidLeg(format!(
+                        "no coupon rate effective for the period starting {}",
+                        p.start()
+                    ))
+                })?;
+                let notional = self.notional.or(p.notional()).ok_or_else(|| {
+                    CashflowError::InvalidLeg(format!(
+                        "no notional for the period starting {}; set one or amortize \
+                         the schedule",
+                        p.start()
+                    ))
+                })?;
+                let (accrual_start, accrual_end) = p.accrual_dates(self.accrual);
+                Ok(Cashflow {
+                    accrual_start,
+                    accrual_end,
+                    accrual_factor,
+                    pay: p.pay(),
+                    notional,
+                    rate,
+                    amount: notional * rate * accrual_factor,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bizdate::{BusdayConvention, BusinessCalendar};
+    use chrono::NaiveDate;
+    use scheduling::{AccrualDates, Amortization, Daycounts, Period, Schedule};
+
+    use super::{CouponRate, FixedLeg};
+    use crate::CashflowError;
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn get_cal() -> BusinessCalendar {
+        BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100")
+    }
+
+    // * semi-annual 2026-02-15 to 2028-02-15; 2026-08-15 and 2027-02-15 are a Saturday
+    // * and a Monday, 2027-08-15 a Sunday
+    fn schedule(cal: &BusinessCalendar) -> Schedule<'_> {
+        Schedule::builder()
+            .effective(ymd("2026-02-15"))
+            .termination(ymd("2028-02-15"))
+            .period(Period::Months(6))
+            .accrual_calendar(cal)
+            .accrual_convention(BusdayConvention::Following)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_bond() {
+        // * 5% 30/360 bond accruing on unadjusted dates pays exactly 2.5 per 100
+        let cal = get_cal();
+        let sch = schedule(&cal);
+        let leg = FixedLeg::new(&sch, CouponRate::Constant(0.05), Daycounts::Thirty360)
+            .notional(100.0);
+        let cfs = leg.cashflows().unwrap();
+        assert_eq!(cfs.len(), 4);
+        for (cf, p) in cfs.iter().zip(&sch) {
+            assert_eq!(cf.amount(), 2.5);
+            assert_eq!(cf.accrual_factor(), 0.5);
+            assert_eq!(cf.accrual_start(), p.start());
+            assert_eq!(cf.accrual_end(), p.end());
+            assert_eq!(cf.pay(), p.pay());
+        }
+        assert_eq!(cfs[0].pay(), ymd("2026-08-17"));
+    }
+
+    #[test]
+    fn test_adjusted_accrual() {
+        // * swap style: Act/360 on adjusted dates, 2026-02-16 to 2026-08-17 is 182 days
+        let cal = get_cal();
+        let sch = schedule(&cal);
+        let leg = FixedLeg::new(&sch, CouponRate::Constant(0.04), Daycounts::Act360)
+            .notional(1_000_000.0)
+            .accrual_dates(AccrualDates::Adjusted);
+        let cfs = leg.cashflows().unwrap();
+        assert_eq!(cfs[0].accrual_start(), ymd("2026-02-16"));
+        assert_eq!(cfs[0].accrual_end(), ymd("2026-08-17"));
+        assert_eq!(cfs[0].amount(), 1_000_000.0 * 0.04 * 182.0 / 360.0);
+
+        // * unadjusted accrual is 181 days
+        let leg = FixedLeg::new(&sch, CouponRate::Constant(0.04), Daycounts::Act360)
+            .notional(1_000_000.0);
+        let cfs = leg.cashflows().unwrap();
+        assert_eq!(cfs[0].amount(), 1_000_000.0 * 0.04 * 181.0 / 360.0);
+    }
+
+    #[test]
+    fn test_step_up() {
+        let cal = get_cal();
+        let sch = schedule(&cal);
+        let rate = CouponRate::StepUp(vec![
+            (ymd("2026-02-15"), 0.03),
+            (ymd("2027-02-15"), 0.04),
+        ]);
+        let leg = FixedLeg::new(&sch, rate, Daycounts::Thirty360).notional(100.0);
+        let amounts: Vec<_> = leg
+            .cashflows()
+            .unwrap()
+            .iter()
+            .map(|cf| cf.amount())
+            .collect();
+        assert_eq!(amounts, [1.5, 1.5, 2.0, 2.0]);
+
+        let rate = CouponRate::StepUp(vec![(ymd("2026-08-15"), 0.03)]);
+        let leg = FixedLeg::new(&sch, rate, Daycounts::Thirty360).notional(100.0);
+        assert!(matches!(leg.cashflows(), Err(CashflowError::InvalidLeg(_))));
+
+        let rate = CouponRate::StepUp(vec![
+            (ymd("2027-02-15"), 0.04),
+            (ymd("2026-02-15"), 0.03),
+        ]);
+        let leg = FixedLeg::new(&sch, rate, Daycounts::Thirty360).notional(100.0);
+        assert!(matches!(leg.cashflows(), Err(CashflowError::InvalidLeg(_))));
+    }
+
+    #[test]
+    fn test_amortizing() {
+        let cal = get_cal();
+        let sch = schedule(&cal)
+            .amortize(100.0, &Amortization::Linear)
+            .unwrap();
+        let leg = FixedLeg::new(&sch, CouponRate::Constant(0.04), Daycounts::Thirty360);
+        let cfs = leg.cashflows().unwrap();
+        let notionals: Vec<_> = cfs.iter().map(|cf| cf.notional()).collect();
+        assert_eq!(notionals, [100.0, 75.0, 50.0, 25.0]);
+        let amounts: Vec<_> = cfs.iter().map(|cf| cf.amount()).collect();
+        assert_eq!(amounts, [2.0, 1.5, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_missing_notional() {
+        let cal = get_cal();
+        let sch = schedule(&cal);
+        let leg = FixedLeg::new(&sch, CouponRate::Constant(0.04), Daycounts::Thirty360);
+        assert!(matches!(leg.cashflows(), Err(CashflowError::InvalidLeg(_))));
+    }
+}
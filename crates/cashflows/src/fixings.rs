@@ -0,0 +1,58 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::NaiveDate;
+
+/// Source of published fixings, e.g. daily overnight rates or an index such as the
+/// SOFR Index. Implement over whatever time series store holds the data.
+pub trait FixingSource {
+    /// Get the value published for ``dt``, if it has fixed.
+    fn fixing(&self, dt: NaiveDate) -> Option<f64>;
+}
+
+impl FixingSource for BTreeMap<NaiveDate, f64> {
+    fn fixing(&self, dt: NaiveDate) -> Option<f64> {
+        self.get(&dt).copied()
+    }
+}
+
+impl FixingSource for HashMap<NaiveDate, f64> {
+    fn fixing(&self, dt: NaiveDate) -> Option<f64> {
+        self.get(&dt).copied()
+    }
+}
+
+/// Projection of rates that have not fixed yet, e.g. off a forward curve.
+pub trait RateProjection {
+    /// Get the projected rate for ``dt``.
+    fn project(&self, dt: NaiveDate) -> f64;
+}
+
+impl<F: Fn(NaiveDate) -> f64> RateProjection for F {
+    fn project(&self, dt: NaiveDate) -> f64 {
+        self(dt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+
+    use super::{FixingSource, RateProjection};
+
+    #[test]
+    fn test_map_source() {
+        let dt = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let fixings = BTreeMap::from([(dt, 0.0365)]);
+        assert_eq!(fixings.fixing(dt), Some(0.0365));
+        assert_eq!(fixings.fixing(dt.succ_opt().unwrap()), None);
+    }
+
+    #[test]
+    fn test_closure_projection() {
+        let dt = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let flat = |_| 0.04;
+        assert_eq!(flat.project(dt), 0.04);
+    }
+}
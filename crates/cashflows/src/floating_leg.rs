@@ -0,0 +1,444 @@
+use chrono::NaiveDate;
+use scheduling::{AccrualDates, Daycounts, ResetPeriod, Schedule};
+
+use crate::{Cashflow, CashflowError, CouponRate, FixingSource, RateProjection};
+
+/// The index rate of a single reset and where it came from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ResetFixing {
+    pub(crate) fixing: NaiveDate,
+    pub(crate) rate: f64,
+    pub(crate) projected: bool,
+}
+
+impl ResetFixing {
+    /// Get the date the rate fixes on.
+    pub fn fixing(&self) -> NaiveDate {
+        self.fixing
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Check whether the rate was projected rather than published.
+    pub fn is_projected(&self) -> bool {
+        self.projected
+    }
+}
+
+/// A floating rate coupon along with every input to its rate, i.e.
+/// $\min(\max(g \times r + s, floor), cap)$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatingCoupon {
+    pub(crate) cashflow: Cashflow,
+    pub(crate) fixings: Vec<ResetFixing>,
+    pub(crate) index_rate: f64,
+    pub(crate) gearing: f64,
+    pub(crate) spread: f64,
+    pub(crate) cap: Option<f64>,
+    pub(crate) floor: Option<f64>,
+}
+
+impl FloatingCoupon {
+    /// Get the cashflow paid, whose rate is the all-in coupon rate.
+    pub fn cashflow(&self) -> &Cashflow {
+        &self.cashflow
+    }
+
+    /// Get the fixings of every reset in the period.
+    pub fn fixings(&self) -> &[ResetFixing] {
+        &self.fixings
+    }
+
+    /// Get the date of the first fixing of the period.
+    pub fn fixing(&self) -> NaiveDate {
+        self.fixings[0].fixing
+    }
+
+    /// Get the index rate, averaged over the resets by days where there are several.
+    pub fn index_rate(&self) -> f64 {
+        self.index_rate
+    }
+
+    pub fn gearing(&self) -> f64 {
+        self.gearing
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    pub fn cap(&self) -> Option<f64> {
+        self.cap
+    }
+
+    pub fn floor(&self) -> Option<f64> {
+        self.floor
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.cashflow.rate
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.cashflow.amount
+    }
+}
+
+/// Leg paying gearing x index + spread on every period of a [`Schedule`], optionally
+/// capped and floored.
+///
+/// The schedule must be built with fixing dates (see
+/// [`scheduling::ScheduleBuilder::fixing_offset`]). Notional and accrual work as for
+/// [`crate::FixedLeg`].
+pub struct FloatingLeg<'s, 'a> {
+    schedule: &'s Schedule<'a>,
+    daycount: Daycounts<'a>,
+    gearing: f64,
+    spread: f64,
+    cap: Option<CouponRate>,
+    floor: Option<CouponRate>,
+    notional: Option<f64>,
+    accrual: AccrualDates,
+}
+
+impl<'s, 'a> FloatingLeg<'s, 'a> {
+    pub fn new(schedule: &'s Schedule<'a>, daycount: Daycounts<'a>) -> Self {
+        Self {
+            schedule,
+            daycount,
+            gearing: 1.0,
+            spread: 0.0,
+            cap: None,
+            floor: None,
+            notional: None,
+            accrual: AccrualDates::default(),
+        }
+    }
+
+    /// Set the multiplier on the index rate. Defaults to 1.
+    pub fn gearing(mut self, gearing: f64) -> Self {
+        self.gearing = gearing;
+        self
+    }
+
+    pub fn spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set the maximum coupon rate, constant or stepping by period start.
+    pub fn cap(mut self, cap: CouponRate) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    /// Set the minimum coupon rate, constant or stepping by period start.
+    pub fn floor(mut self, floor: CouponRate) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    /// Set a constant notional in place of the schedule's amortization profile.
+    pub fn notional(mut self, notional: f64) -> Self {
+        self.notional = Some(notional);
+        self
+    }
+
+    /// Set which period dates accrual is measured between.
+    pub fn accrual_dates(mut self, accrual: AccrualDates) -> Self {
+        self.accrual = accrual;
+        self
+    }
+
+    /// Generate the coupon of every period as of ``as_of``, taking published rates
+    /// from ``fixings`` and rates fixing after ``as_of`` from ``projection``. A fixing
+    /// on or before ``as_of`` missing from ``fixings`` is an error.
+    pub fn coupons<F: FixingSource + ?Sized>(
+        &self,
+        as_of: NaiveDate,
+        fixings: &F,
+        projection: Option<&dyn RateProjection>,
+    ) -> Result<Vec<FloatingCoupon>, CashflowError> {
+        let factors = self
+            .daycount
+            .schedule_year_fractions(self.schedule, self.accrual);
+        self.schedule
+            .iter()
+            .zip(factors)
+            .map(|(p, accrual_factor)| {
+                if p.resets().is_empty() {
+                    return Err(CashflowError::InvalidLeg(format!(
+                        "period starting {} has no fixing dates",
+                        p.start()
+                    )));
+                }
+                let fixed = p
+                    .resets()
+                    .iter()
+                    .map(|r| fix(r, as_of, fixings, projection))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let index_rate = self.average(p.resets(), &fixed);
+
+                let bound = |rate: &Option<CouponRate>| match rate {
+                    Some(rate) => rate.rate(p.start()).map(Some).ok_or_else(|| {
+                        CashflowError::InvalidLeg(format!(
+                            "no cap or floor effective for the period starting {}",
+                            p.start()
+                        ))
+                    }),
+                    None => Ok(None),
+                };
+                let (cap, floor) = (bound(&self.cap)?, bound(&self.floor)?);
+                if let (Some(cap), Some(floor)) = (cap, floor)
+                    && cap < floor
+                {
+                    return Err(CashflowError::InvalidLeg(format!(
+                        "cap {cap} is below floor {floor}"
+                    )));
+                }
+                let mut rate = self.gearing * index_rate + self.spread;
+                if let Some(floor) = floor {
+                    rate = rate.max(floor);
+                }
+                if let Some(cap) = cap {
+                    rate = rate.min(cap);
+                }
+
+                let notional = self.notional.or(p.notional()).ok_or_else(|| {
+                    CashflowError::InvalidLeg(format!(
+                        "no notional for the period starting {}; set one or amortize \
+                         the schedule",
+                        p.start()
+                    ))
+                })?;
+                let (accrual_start, accrual_end) = p.accrual_dates(self.accrual);
+                Ok(FloatingCoupon {
+                    cashflow: Cashflow {
+                        accrual_start,
+                        accrual_end,
+                        accrual_factor,
+                        pay: p.pay(),
+                        notional,
+                        rate,
+                        amount: notional * rate * accrual_factor,
+                    },
+                    fixings: fixed,
+                    index_rate,
+                    gearing: self.gearing,
+                    spread: self.spread,
+                    cap,
+                    floor,
+                })
+            })
+            .collect()
+    }
+
+    /// Average the reset rates weighted by the days each reset accrues over.
+    fn average(&self, resets: &[ResetPeriod], fixed: &[ResetFixing]) -> f64 {
+        if let [fixing] = fixed {
+            return fixing.rate;
+        }
+        let days = |r: &ResetPeriod| match self.accrual {
+            AccrualDates::Unadjusted => (r.end() - r.start()).num_days(),
+            AccrualDates::Adjusted => (r.end_adj() - r.start_adj()).num_days(),
+        } as f64;
+        let total: f64 = resets.iter().map(days).sum();
+        resets
+            .iter()
+            .zip(fixed)
+            .map(|(r, f)| f.rate * days(r))
+            .sum::<f64>()
+            / total
+    }
+}
+
+/// Look up the rate of a reset, projecting it if it fixes after ``as_of``.
+fn fix<F: FixingSource + ?Sized>(
+    reset: &ResetPeriod,
+    as_of: NaiveDate,
+    fixings: &F,
+    projection: Option<&dyn RateProjection>,
+) -> Result<ResetFixing, CashflowError> {
+    let dt = reset.fixing();
+    let (rate, projected) = match (fixings.fixing(dt), projection) {
+        (Some(rate), _) => (rate, false),
+        (None, Some(projection)) if dt > as_of => (projection.project(dt), true),
+        (None, _) => return Err(CashflowError::MissingFixing(dt)),
+    };
+    Ok(ResetFixing {
+        fixing: dt,
+        rate,
+        projected,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use bizdate::{BusdayConvention, BusinessCalendar};
+    use chrono::NaiveDate;
+    use scheduling::{Daycounts, Offset, Period, Schedule};
+
+    use super::FloatingLeg;
+    use crate::{CashflowError, CouponRate, RateProjection};
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn get_cal() -> BusinessCalendar {
+        BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100")
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+
+    // * quarterly from 2026-03-16, fixing 2 business days before each period start:
+    // * 2026-03-12, 2026-06-12, 2026-09-14 and 2026-12-14
+    fn schedule(cal: &BusinessCalendar) -> Schedule<'_> {
+        Schedule::builder()
+            .effective(ymd("2026-03-16"))
+            .termination(ymd("2027-03-16"))
+            .period(Period::Months(3))
+            .accrual_calendar(cal)
+            .accrual_convention(BusdayConvention::ModifiedFollowing)
+            .fixing_offset(Offset::Before(Period::BusDays(2, cal)))
+            .build()
+            .unwrap()
+    }
+
+    // * valuation date after the second fixing
+    const AS_OF: NaiveDate = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+
+    fn fixings() -> BTreeMap<NaiveDate, f64> {
+        BTreeMap::from([(ymd("2026-03-12"), 0.030), (ymd("2026-06-12"), 0.032)])
+    }
+
+    #[test]
+    fn test_coupons() {
+        let cal = get_cal();
+        let sch = schedule(&cal);
+        let leg = FloatingLeg::new(&sch, Daycounts::Act360)
+            .notional(1_000_000.0)
+            .gearing(1.5)
+            .spread(0.001);
+        let projection = |_| 0.035;
+        let coupons = leg
+            .coupons(AS_OF, &fixings(), Some(&projection as &dyn RateProjection))
+            .unwrap();
+        assert_eq!(coupons.len(), 4);
+
+        // * 2026-03-16 to 2026-06-16 is 92 days
+        let first = &coupons[0];
+        assert_eq!(first.fixing(), ymd("2026-03-12"));
+        assert!(!first.fixings()[0].is_projected());
+        assert_eq!(first.index_rate(), 0.030);
+        assert_close(first.rate(), 1.5 * 0.030 + 0.001);
+        assert_close(first.amount(), 1_000_000.0 * first.rate() * 92.0 / 360.0);
+        assert_eq!(first.cashflow().pay(), ymd("2026-06-16"));
+
+        assert_eq!(coupons[1].index_rate(), 0.032);
+        assert_eq!(coupons[2].fixing(), ymd("2026-09-14"));
+        assert!(coupons[2].fixings()[0].is_projected());
+        assert_eq!(coupons[2].index_rate(), 0.035);
+    }
+
+    #[test]
+    fn test_missing_fixing() {
+        let cal = get_cal();
+        let sch = schedule(&cal);
+        let leg = FloatingLeg::new(&sch, Daycounts::Act360).notional(100.0);
+        let rslt = leg.coupons(AS_OF, &fixings(), None);
+        assert_eq!(rslt, Err(CashflowError::MissingFixing(ymd("2026-09-14"))));
+
+        // * a past fixing is never projected
+        let projection = |_| 0.035;
+        let projection = Some(&projection as &dyn RateProjection);
+        let rslt = leg.coupons(ymd("2026-09-14"), &fixings(), projection);
+        assert_eq!(rslt, Err(CashflowError::MissingFixing(ymd("2026-09-14"))));
+        let rslt = leg.coupons(ymd("2026-09-11"), &fixings(), projection);
+        assert!(rslt.is_ok());
+
+        let leg = FloatingLeg::new(&sch, Daycounts::Act360);
+        let rslt = leg.coupons(AS_OF, &fixings(), projection);
+        assert!(matches!(rslt, Err(CashflowError::InvalidLeg(_))));
+    }
+
+    #[test]
+    fn test_cap_floor() {
+        let cal = get_cal();
+        let sch = schedule(&cal);
+        let projection = |_| -0.01;
+        let leg = FloatingLeg::new(&sch, Daycounts::Act360)
+            .notional(100.0)
+            .spread(0.005)
+            .floor(CouponRate::Constant(0.0))
+            .cap(CouponRate::StepUp(vec![
+                (ymd("2026-03-16"), 0.031),
+                (ymd("2026-06-16"), 0.05),
+            ]));
+        let coupons = leg
+            .coupons(AS_OF, &fixings(), Some(&projection as &dyn RateProjection))
+            .unwrap();
+        let rates: Vec<_> = coupons.iter().map(|c| c.rate()).collect();
+        // * 3.5% capped at 3.1%, 3.7% under the stepped up 5% cap, then floored at 0
+        assert_close(rates[0], 0.031);
+        assert_close(rates[1], 0.037);
+        assert_eq!(rates[2..], [0.0, 0.0]);
+        assert_eq!(coupons[0].cap(), Some(0.031));
+        assert_eq!(coupons[2].floor(), Some(0.0));
+
+        let leg = FloatingLeg::new(&sch, Daycounts::Act360)
+            .notional(100.0)
+            .cap(CouponRate::Constant(0.01))
+            .floor(CouponRate::Constant(0.02));
+        let rslt =
+            leg.coupons(AS_OF, &fixings(), Some(&projection as &dyn RateProjection));
+        assert!(matches!(
+            rslt,
+            Err(CashflowError::InvalidLeg(msg)) if msg.contains("below floor")
+        ));
+    }
+
+    #[test]
+    fn test_multiple_resets() {
+        // * monthly resets on a quarterly leg average by days: 31, 30 and 31 days
+        let sch = Schedule::builder()
+            .effective(ymd("2026-03-16"))
+            .termination(ymd("2026-06-16"))
+            .period(Period::Months(3))
+            .reset_period(Period::Months(1))
+            .build()
+            .unwrap();
+        let fixings = BTreeMap::from([
+            (ymd("2026-03-16"), 0.03),
+            (ymd("2026-04-16"), 0.04),
+            (ymd("2026-05-16"), 0.05),
+        ]);
+        let leg = FloatingLeg::new(&sch, Daycounts::Act360).notional(100.0);
+        let coupons = leg.coupons(ymd("2026-05-16"), &fixings, None).unwrap();
+        assert_eq!(coupons[0].fixings().len(), 3);
+        let expected = (0.03 * 31.0 + 0.04 * 30.0 + 0.05 * 31.0) / 92.0;
+        assert_close(coupons[0].index_rate(), expected);
+    }
+
+    #[test]
+    fn test_no_resets() {
+        let cal = get_cal();
+        let sch = Schedule::builder()
+            .effective(ymd("2026-03-16"))
+            .termination(ymd("2027-03-16"))
+            .period(Period::Months(3))
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::Following)
+            .build()
+            .unwrap();
+        let leg = FloatingLeg::new(&sch, Daycounts::Act360);
+        let rslt = leg.coupons(AS_OF, &fixings(), None);
+        assert!(matches!(rslt, Err(CashflowError::InvalidLeg(_))));
+    }
+}
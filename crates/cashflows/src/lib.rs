@@ -0,0 +1,45 @@
+pub mod accrued;
+pub mod cashflow;
+pub mod compounding;
+pub mod fixed_leg;
+pub mod fixings;
+pub mod floating_leg;
+
+use std::fmt;
+
+use chrono::NaiveDate;
+use scheduling::SchedulingError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CashflowError {
+    MissingFixing(NaiveDate),
+    InvalidPeriod(String),
+    InvalidLeg(String),
+    Scheduling(SchedulingError),
+}
+
+impl fmt::Display for CashflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingFixing(dt) => write!(f, "missing fixing for {dt}"),
+            Self::InvalidPeriod(msg) => write!(f, "invalid period: {msg}"),
+            Self::InvalidLeg(msg) => write!(f, "invalid leg: {msg}"),
+            Self::Scheduling(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<SchedulingError> for CashflowError {
+    fn from(err: SchedulingError) -> Self {
+        Self::Scheduling(err)
+    }
+}
+
+impl std::error::Error for CashflowError {}
+
+pub use accrued::AccruedInterest;
+pub use cashflow::Cashflow;
+pub use compounding::{OvernightRate, RateAveraging};
+pub use fixed_leg::{CouponRate, FixedLeg};
+pub use fixings::{FixingSource, RateProjection};
+pub use floating_leg::{FloatingCoupon, FloatingLeg, ResetFixing};
@@ -0,0 +1,56 @@
+//! Compare per-pair year fractions against the batched schedule path.
+//!
+//! Run with ``cargo bench -p scheduling --bench year_fractions``.
+
+use std::hint::black_box;
+
+use bizdate::{BusdayConvention, BusinessCalendar};
+use chrono::NaiveDate;
+use criterion::{Criterion, criterion_group, criterion_main};
+use scheduling::{AccrualDates, Daycounts, Period, Schedule};
+
+fn holidays() -> Vec<NaiveDate> {
+    (1990..=2090)
+        .flat_map(|year| {
+            [
+                NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(year, 7, 4).unwrap(),
+                NaiveDate::from_ymd_opt(year, 12, 25).unwrap(),
+            ]
+        })
+        .collect()
+}
+
+fn year_fractions(c: &mut Criterion) {
+    let cal = BusinessCalendar::new(Some(holidays()), "1111100");
+    let sch = Schedule::builder()
+        .effective(NaiveDate::from_ymd_opt(1990, 1, 15).unwrap())
+        .termination(NaiveDate::from_ymd_opt(2090, 1, 15).unwrap())
+        .period(Period::Months(1))
+        .accrual_calendar(&cal)
+        .accrual_convention(BusdayConvention::ModifiedFollowing)
+        .build()
+        .unwrap();
+    let pairs: Vec<_> = sch
+        .iter()
+        .map(|p| p.accrual_dates(AccrualDates::Adjusted))
+        .collect();
+
+    let mut group = c.benchmark_group(format!("bus252_{}_periods", pairs.len()));
+    let dc = Daycounts::Bus252(&cal);
+    group.bench_function("per_pair", |b| {
+        b.iter(|| {
+            pairs
+                .iter()
+                .map(|&(start, end)| dc.try_year_fraction(start, end).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| dc.schedule_year_fractions(black_box(&sch), AccrualDates::Adjusted))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, year_fractions);
+criterion_main!(benches);
@@ -1,6 +1,9 @@
+use std::fmt;
+
 use bizdate::{BusdayConvention, BusinessCalendar};
 use chrono::NaiveDate;
 
+#[derive(Clone, Copy)]
 pub struct Adjuster<'t> {
     buscal: &'t BusinessCalendar,
     conv: BusdayConvention,
@@ -15,3 +18,16 @@ impl<'t> Adjuster<'t> {
         self.buscal.adjust(dt, self.conv)
     }
 }
+
+impl<'t> fmt::Debug for Adjuster<'t> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Adjuster").finish_non_exhaustive()
+    }
+}
+
+// * calendars are compared by identity, as for periods in business days
+impl<'t> PartialEq for Adjuster<'t> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.buscal, other.buscal) && self.conv as u8 == other.conv as u8
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{Daycounts, Schedule, SchedulingError};
+
+/// Profile of the outstanding notional over the periods of a schedule.
+///
+/// Whatever the profile, the principal repaid at the end of the final period is the
+/// notional still outstanding, so the notional always reconciles to zero at maturity.
+pub enum Amortization<'t> {
+    /// Full notional outstanding until maturity
+    Bullet,
+    /// Equal principal repaid at the end of each period
+    Linear,
+    /// Level payment of principal plus interest accrued at ``rate`` with ``daycount``,
+    /// as for a mortgage
+    Annuity { rate: f64, daycount: Daycounts<'t> },
+    /// Notional outstanding in each period, starting at the original notional
+    Custom(Vec<f64>),
+    /// Notional outstanding in each period as a fraction of the original, starting at
+    /// 1 and never increasing, e.g. 0.75
+    StepDown(Vec<f64>),
+}
+
+impl<'t> Amortization<'t> {
+    /// Get the notional outstanding in each period of ``schedule`` given the original
+    /// ``notional``.
+    pub fn notionals(
+        &self,
+        notional: f64,
+        schedule: &Schedule,
+    ) -> Result<Vec<f64>, SchedulingError> {
+        let n = schedule.len();
+        let notionals = match self {
+            Self::Bullet => vec![notional; n],
+            Self::Linear => (0..n)
+                .map(|i| notional * (n - i) as f64 / n as f64)
+                .collect(),
+            Self::Annuity { rate, daycount } => {
+                let factors: Vec<f64> = schedule
+                    .iter()
+                    .map(|p| 1.0 + rate * daycount.period_year_fraction(p, schedule))
+                    .collect();
+                annuity(notional, &factors)
+            }
+            Self::Custom(notionals) => notionals.clone(),
+            Self::StepDown(fractions) => {
+                fractions.iter().map(|f| notional * f).collect()
+            }
+        };
+
+        if notionals.len() != n {
+            return Err(SchedulingError::InvalidSchedule(format!(
+                "amortization has {} notionals for {n} periods",
+                notionals.len()
+            )));
+        }
+        if let Self::Custom(custom) = self
+            && custom.first().is_some_and(|x| *x != notional)
+        {
+            return Err(SchedulingError::InvalidSchedule(format!(
+                "custom amortization must start at the original notional {notional}"
+            )));
+        }
+        if let Self::StepDown(fractions) = self {
+            if fractions.first().is_some_and(|f| *f != 1.0) {
+                return Err(SchedulingError::InvalidSchedule(
+                    "step-down amortization must start at a fraction of 1".to_string(),
+                ));
+            }
+            if fractions.windows(2).any(|w| w[1] > w[0]) {
+                return Err(SchedulingError::InvalidSchedule(format!(
+                    "step-down fractions {fractions:?} must not increase"
+                )));
+            }
+        }
+        if let Some(x) = notionals.iter().find(|x| !x.is_finite() || **x < 0.0) {
+            return Err(SchedulingError::InvalidSchedule(format!(
+                "outstanding notional {x} must be finite and non-negative"
+            )));
+        }
+        Ok(notionals)
+    }
+}
+
+/// Get the outstanding notionals of a level-payment loan given the growth factor of
+/// each period, i.e. $1 + r \tau_i$.
+fn annuity(notional: f64, factors: &[f64]) -> Vec<f64> {
+    // * the payment P solves N = sum_k P / prod_{j <= k} f_j
+    let mut discount = 1.0;
+    let mut annuity = 0.0;
+    for f in factors {
+        discount /= f;
+        annuity += discount;
+    }
+    let payment = notional / annuity;
+
+    let mut outstanding = notional;
+    factors
+        .iter()
+        .map(|f| {
+            let current = outstanding;
+            outstanding = current * f - payment;
+            current
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::Amortization;
+    use crate::{Daycounts, Period, Schedule, SchedulingError};
+
+    fn schedule<'a>() -> Schedule<'a> {
+        Schedule::builder()
+            .effective(NaiveDate::from_ymd_opt(2026, 1, 15).unwrap())
+            .termination(NaiveDate::from_ymd_opt(2027, 1, 15).unwrap())
+            .period(Period::Months(3))
+            .build()
+            .unwrap()
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    fn assert_reconciles(sch: &Schedule, notional: f64) {
+        let principal: f64 = sch.iter().map(|p| p.principal()).sum();
+        assert_close(principal, notional);
+        for w in sch.periods().windows(2) {
+            let (current, next) = (w[0].notional().unwrap(), w[1].notional().unwrap());
+            assert_close(current - w[0].principal(), next);
+        }
+        let last = sch.last().unwrap();
+        assert_eq!(Some(last.principal()), last.notional());
+    }
+
+    #[test]
+    fn test_unamortized() {
+        let sch = schedule();
+        assert!(sch.iter().all(|p| p.notional().is_none()));
+        assert_eq!(sch.notionals().count(), 0);
+    }
+
+    #[test]
+    fn test_bullet() {
+        let sch = schedule().amortize(100.0, &Amortization::Bullet).unwrap();
+        let principal: Vec<_> = sch.iter().map(|p| p.principal()).collect();
+        assert_eq!(principal, [0.0, 0.0, 0.0, 100.0]);
+        assert!(sch.iter().all(|p| p.notional() == Some(100.0)));
+        assert_reconciles(&sch, 100.0);
+    }
+
+    #[test]
+    fn test_linear() {
+        let sch = schedule().amortize(100.0, &Amortization::Linear).unwrap();
+        let notionals: Vec<_> = sch.notionals().collect();
+        assert_eq!(notionals, [100.0, 75.0, 50.0, 25.0]);
+        assert!(sch.iter().all(|p| p.principal() == 25.0));
+        assert_reconciles(&sch, 100.0);
+    }
+
+    #[test]
+    fn test_annuity() {
+        let amortization = Amortization::Annuity {
+            rate: 0.06,
+            daycount: Daycounts::Thirty360,
+        };
+        let sch = schedule().amortize(100_000.0, &amortization).unwrap();
+        assert_reconciles(&sch, 100_000.0);
+
+        // * quarterly 30/360 accrual makes every period 1.5%; the level payment is
+        // * the textbook annuity 100000 * 0.015 / (1 - 1.015^-4)
+        let payment = 100_000.0 * 0.015 / (1.0 - 1.015_f64.powi(-4));
+        for p in &sch {
+            assert_close(p.notional().unwrap() * 0.015 + p.principal(), payment);
+        }
+
+        let amortization = Amortization::Annuity {
+            rate: 0.0,
+            daycount: Daycounts::Act360,
+        };
+        let sch = schedule().amortize(100.0, &amortization).unwrap();
+        let notionals: Vec<_> = sch.notionals().collect();
+        for (a, b) in notionals.iter().zip([100.0, 75.0, 50.0, 25.0]) {
+            assert_close(*a, b);
+        }
+    }
+
+    #[test]
+    fn test_custom() {
+        let amortization = Amortization::Custom(vec![100.0, 100.0, 60.0, 10.0]);
+        let sch = schedule().amortize(100.0, &amortization).unwrap();
+        let principal: Vec<_> = sch.iter().map(|p| p.principal()).collect();
+        assert_eq!(principal, [0.0, 40.0, 50.0, 10.0]);
+        assert_reconciles(&sch, 100.0);
+    }
+
+    #[test]
+    fn test_step_down() {
+        let amortization = Amortization::StepDown(vec![1.0, 0.8, 0.6, 0.4]);
+        let sch = schedule().amortize(50.0, &amortization).unwrap();
+        let notionals: Vec<_> = sch.notionals().collect();
+        assert_eq!(notionals, [50.0, 40.0, 30.0, 20.0]);
+        assert_eq!(sch.last().unwrap().principal(), 20.0);
+        assert_reconciles(&sch, 50.0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        for amortization in [
+            Amortization::Custom(vec![100.0, 50.0]),
+            Amortization::Custom(vec![100.0, -50.0, 0.0, 0.0]),
+            Amortization::Custom(vec![90.0, 50.0, 10.0, 0.0]),
+            Amortization::StepDown(vec![1.0, f64::NAN, 0.5, 0.5]),
+            Amortization::StepDown(vec![0.9, 0.8, 0.6, 0.4]),
+            Amortization::StepDown(vec![1.0, 0.6, 0.8, 0.4]),
+        ] {
+            let rslt = schedule().amortize(100.0, &amortization);
+            assert!(matches!(rslt, Err(SchedulingError::InvalidSchedule(_))));
+        }
+    }
+}
@@ -1,37 +1,351 @@
-use bizdate::BusinessCalendar;
-use chrono::{Datelike, NaiveDate};
+use std::fmt;
+use std::str::FromStr;
+
+use bizdate::{BusdayConvention, BusinessCalendar};
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+use crate::{AccrualDates, Roll, Schedule, SchedulePeriod, SchedulingError};
+
+/// Tolerance used when comparing year fractions against a target.
+const YF_TOL: f64 = 1e-12;
+
+/// Upper bound on the search horizon of [`Daycounts::inverse_year_fraction`] in days.
+const MAX_SEARCH_DAYS: u64 = 200 * 366;
 
 pub enum Daycounts<'t> {
     Act360,
+    /// Act/365 Actual: days falling in leap years over 366, all others over 365
     Act365,
     Act365F,
+    /// NL/365: actual days excluding any 29 Feb in $(start, end]$, over 365
+    Nl365,
+    /// Act/364
+    Act364,
+    /// Act/365.25
+    Act36525,
     ActActIsda,
-    // Act365L, // TODO: requires Schedule
+    /// Act/Act AFB: whole years counted back from the end date plus the residual over
+    /// 366 if it contains 29 Feb, otherwise 365
+    ActActAfb,
+    /// Act/Act ICMA (ICMA Rule 251); only defined over the reference periods of a
+    /// schedule, see [`Daycounts::period_year_fraction`]
+    ActActIcma,
+    /// Act/365L (ISMA-Year); see [`Daycounts::period_year_fraction`]
+    Act365L,
     Bus252(&'t BusinessCalendar),
+    /// 30/360 US (Bond Basis), including the February end-of-month rules
+    Thirty360,
+    /// 30E/360 (Eurobond Basis)
+    Thirty360E,
+    /// 30E/360 ISDA; carries the termination date of the instrument
+    Thirty360EIsda(NaiveDate),
+    /// 30E+/360
+    Thirty360EPlus,
 }
 
 impl<'t> Daycounts<'t> {
+    /// Compute the year fraction between ``start`` and ``end``.
+    ///
+    /// # Panics
+    ///
+    /// Panics for Act/Act ICMA, which needs a reference period; see
+    /// [`Daycounts::try_year_fraction`].
+    #[deprecated(note = "panics for Act/Act ICMA; use `try_year_fraction`")]
     pub fn year_fraction(&self, start: NaiveDate, end: NaiveDate) -> f64 {
-        match *self {
+        self.try_year_fraction(start, end)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Compute the year fraction between ``start`` and ``end``, or an error for
+    /// Act/Act ICMA, which is only defined over the reference periods of a schedule
+    /// (see [`Daycounts::period_year_fraction`]).
+    pub fn try_year_fraction(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<f64, SchedulingError> {
+        let yf = match *self {
             Self::Act360 => act360(start, end),
             Self::Act365 => act365(start, end),
             Self::Act365F => act365f(start, end),
+            Self::Nl365 => nl365(start, end),
+            Self::Act364 => act364(start, end),
+            Self::Act36525 => act36525(start, end),
+            Self::Act365L => act365l(start, end, false),
             Self::ActActIsda => act_act_isda(start, end),
+            Self::ActActAfb => act_act_afb(start, end),
+            Self::ActActIcma => {
+                return Err(SchedulingError::InvalidDaycount(
+                    "Act/Act ICMA needs the reference periods of a schedule"
+                        .to_string(),
+                ));
+            }
             Self::Bus252(cal) => bus252(start, end, cal),
+            Self::Thirty360 => thirty360(start, end),
+            Self::Thirty360E => thirty360e(start, end),
+            Self::Thirty360EIsda(termination) => {
+                thirty360e_isda(start, end, termination)
+            }
+            Self::Thirty360EPlus => thirty360e_plus(start, end),
+        };
+        Ok(yf)
+    }
+
+    /// Compute the year fraction of ``period`` given the ``schedule`` it belongs to.
+    ///
+    /// Conventions that need the coupon frequency or reference periods (Act/365L and
+    /// Act/Act ICMA) take them from the schedule; all others reduce to
+    /// [`Daycounts::try_year_fraction`] over the unadjusted period dates.
+    pub fn period_year_fraction(
+        &self,
+        period: &SchedulePeriod,
+        schedule: &Schedule,
+    ) -> f64 {
+        self.period_year_fraction_on(period, schedule, AccrualDates::Unadjusted)
+    }
+
+    /// Compute the year fraction of every period in ``schedule`` in a single pass.
+    ///
+    /// Equivalent to calling [`Daycounts::period_year_fraction`] on each period, but
+    /// accrual is measured between the ``dates`` requested (Act/Act ICMA always uses
+    /// the unadjusted dates) and Bus/252 walks the calendar only once across the
+    /// whole schedule.
+    pub fn schedule_year_fractions(
+        &self,
+        schedule: &Schedule,
+        dates: AccrualDates,
+    ) -> Vec<f64> {
+        match *self {
+            Self::Bus252(cal) => {
+                let bounds: Vec<_> = schedule
+                    .periods
+                    .iter()
+                    .map(|p| p.accrual_dates(dates))
+                    .collect();
+                bus252_batch(&bounds, cal)
+            }
+            _ => schedule
+                .periods
+                .iter()
+                .map(|p| self.period_year_fraction_on(p, schedule, dates))
+                .collect(),
+        }
+    }
+
+    /// Compute the year fraction from the start of ``period`` to ``dt``, e.g. for
+    /// accrued interest, given the ``schedule`` it belongs to.
+    ///
+    /// Act/Act ICMA accrues over the reference periods of the whole period, so a
+    /// partial regular period is its share of days times $1 / f$.
+    pub fn accrued_year_fraction(
+        &self,
+        period: &SchedulePeriod,
+        schedule: &Schedule,
+        dates: AccrualDates,
+        dt: NaiveDate,
+    ) -> f64 {
+        self.year_fraction_until(period, schedule, dates, dt)
+    }
+
+    fn period_year_fraction_on(
+        &self,
+        period: &SchedulePeriod,
+        schedule: &Schedule,
+        dates: AccrualDates,
+    ) -> f64 {
+        let end = period.accrual_dates(dates).1;
+        self.year_fraction_until(period, schedule, dates, end)
+    }
+
+    fn year_fraction_until(
+        &self,
+        period: &SchedulePeriod,
+        schedule: &Schedule,
+        dates: AccrualDates,
+        until: NaiveDate,
+    ) -> f64 {
+        let start = period.accrual_dates(dates).0;
+        match *self {
+            Self::Act365L => {
+                let annual = schedule.period.frequency().is_some_and(|f| f <= 1.0);
+                act365l(start, until, annual)
+            }
+            Self::ActActIcma => act_act_icma(period, schedule, until),
+            _ => self
+                .try_year_fraction(start, until)
+                .unwrap_or_else(|_| unreachable!("only Act/Act ICMA needs a schedule")),
+        }
+    }
+
+    /// Find the earliest date ``d`` such that ``year_fraction(start, d)`` reaches
+    /// ``yf``, or ``None`` if there is no such date within 200 years or the day count
+    /// needs a schedule (Act/Act ICMA).
+    ///
+    /// Most conventions are non-decreasing in ``d`` and the date is found by bisection.
+    /// The 30/360 month-end rules and the end-year denominator of Act/365L can make
+    /// the year fraction drop slightly as ``d`` advances, so the days preceding the
+    /// bisection result are scanned for an earlier solution as well.
+    pub fn inverse_year_fraction(
+        &self,
+        start: NaiveDate,
+        yf: f64,
+    ) -> Option<NaiveDate> {
+        if !yf.is_finite() {
+            return None;
+        }
+        if yf <= 0.0 {
+            return Some(start);
+        }
+
+        let reached = |days: u64| -> Option<bool> {
+            let dt = start.checked_add_days(Days::new(days))?;
+            Some(self.try_year_fraction(start, dt).ok()? >= yf - YF_TOL)
+        };
+
+        // * bracket the solution by doubling, then bisect for the first crossing
+        let mut hi = 1;
+        while !reached(hi)? {
+            if hi > MAX_SEARCH_DAYS {
+                return None;
+            }
+            hi *= 2;
+        }
+        let mut lo = hi / 2;
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if reached(mid)? {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+
+        // * any drop in the year fraction is at most a few days plus ~1 day per year
+        let window = 7 + yf.ceil() as u64;
+        let mut days = hi;
+        for tmp in hi.saturating_sub(window)..hi {
+            if reached(tmp)? {
+                days = tmp;
+                break;
+            }
+        }
+        start.checked_add_days(Days::new(days))
+    }
+
+    /// Parse a day count from its FpML ``dayCountFraction`` name or a common alias,
+    /// resolving the business calendar for Bus/252 through ``lookup`` and taking the
+    /// termination date for 30E/360 ISDA from ``termination``.
+    ///
+    /// Bus/252 may name its calendar after a colon, e.g. ``"BUS/252:BRSP"``; the code
+    /// (or ``None`` if there is none) is passed to ``lookup``.
+    pub fn parse_with<F>(
+        s: &str,
+        lookup: F,
+        termination: Option<NaiveDate>,
+    ) -> Result<Self, SchedulingError>
+    where
+        F: FnOnce(Option<&str>) -> Option<&'t BusinessCalendar>,
+    {
+        let (name, code) = match s.trim().split_once(':') {
+            Some((name, code)) => (name.trim(), Some(code.trim())),
+            None => (s.trim(), None),
+        };
+
+        let dc = match name.to_uppercase().as_str() {
+            "ACT/360" | "A360" | "A/360" | "ACT360" | "ACTUAL/360" => Self::Act360,
+            "ACT/365" | "A365" | "ACT365" | "ACTUAL/365" => Self::Act365,
+            "ACT/365.FIXED" | "ACT/365F" | "A365F" | "ACT365F" | "ACT/365 FIXED"
+            | "ACTUAL/365 FIXED" => Self::Act365F,
+            "NL/365" | "NL365" | "ACT/365 NL" => Self::Nl365,
+            "ACT/364" | "A364" | "ACT364" => Self::Act364,
+            "ACT/365.25" | "A365.25" | "ACT365.25" => Self::Act36525,
+            "ACT/365L" | "A365L" | "ACT/365.L" | "ISMA-YEAR" => Self::Act365L,
+            "ACT/ACT.ISDA" | "ACT/ACT" | "ACT/ACT ISDA" | "ACTUAL/ACTUAL" | "AA" => {
+                Self::ActActIsda
+            }
+            "ACT/ACT.AFB" | "ACT/ACT AFB" | "ACTUAL/ACTUAL AFB" => Self::ActActAfb,
+            "ACT/ACT.ICMA" | "ACT/ACT.ISMA" | "ACT/ACT ICMA" | "ACT/ACT ISMA"
+            | "ISMA-99" => Self::ActActIcma,
+            "BUS/252" | "BUS252" | "BD/252" => {
+                let Some(cal) = lookup(code) else {
+                    let msg = match code {
+                        Some(code) => {
+                            format!("Unknown business calendar {code} in {s}")
+                        }
+                        None => format!("No business calendar available for {s}"),
+                    };
+                    return Err(SchedulingError::ParseErr(msg));
+                };
+                return Ok(Self::Bus252(cal));
+            }
+            "30/360" | "30U/360" | "30/360 US" | "360/360" | "BOND BASIS" => {
+                Self::Thirty360
+            }
+            "30E/360" | "30/360 ISMA" | "30S/360" | "EUROBOND BASIS" => {
+                Self::Thirty360E
+            }
+            "30E/360.ISDA" | "30E/360 ISDA" | "GERMAN" => {
+                let Some(termination) = termination else {
+                    let msg = format!("No termination date available for {s}");
+                    return Err(SchedulingError::ParseErr(msg));
+                };
+                Self::Thirty360EIsda(termination)
+            }
+            "30E+/360" => Self::Thirty360EPlus,
+            _ => {
+                let msg = format!("Unknown day count convention {s}");
+                return Err(SchedulingError::ParseErr(msg));
+            }
+        };
+
+        match code {
+            Some(_) => {
+                let msg = format!("Only BUS/252 takes a calendar qualifier, got {s}");
+                Err(SchedulingError::ParseErr(msg))
+            }
+            None => Ok(dc),
         }
     }
 }
 
-// impl FromStr for Daycounts {
-//     type Err = SchedulingError;
+impl<'t> FromStr for Daycounts<'t> {
+    type Err = SchedulingError;
+
+    /// Parse a day count that needs neither a business calendar nor a termination
+    /// date; use [`Daycounts::parse_with`] for Bus/252 and 30E/360 ISDA.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |_| None, None)
+    }
+}
 
-//     fn from_str(s: &str) -> Result<Self, Self::Err> {
-//         match s {
-//             "ACT/360" => Ok(Self::Act360),
-//             "ACT/365F" => Ok(Self::Act365F),
-//         }
-//     }
-// }
+impl<'t> fmt::Display for Daycounts<'t> {
+    /// Format as the FpML ``dayCountFraction`` scheme value where FpML defines one,
+    /// otherwise under the convention's common name (e.g. 30E+/360).
+    ///
+    /// The Bus/252 calendar and the 30E/360 ISDA termination date are not part of the
+    /// name; [`Daycounts::parse_with`] given the same calendar and termination date
+    /// parses the name back to the same day count.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Act360 => "ACT/360",
+            Self::Act365 => "ACT/365",
+            Self::Act365F => "ACT/365.FIXED",
+            Self::Nl365 => "NL/365",
+            Self::Act364 => "ACT/364",
+            Self::Act36525 => "ACT/365.25",
+            Self::Act365L => "ACT/365L",
+            Self::ActActIsda => "ACT/ACT.ISDA",
+            Self::ActActAfb => "ACT/ACT.AFB",
+            Self::ActActIcma => "ACT/ACT.ICMA",
+            Self::Bus252(_) => "BUS/252",
+            Self::Thirty360 => "30/360",
+            Self::Thirty360E => "30E/360",
+            Self::Thirty360EIsda(_) => "30E/360.ISDA",
+            Self::Thirty360EPlus => "30E+/360",
+        };
+        f.pad(name)
+    }
+}
 
 fn act360(start: NaiveDate, end: NaiveDate) -> f64 {
     let days = (end - start).num_days() as f64;
@@ -43,13 +357,52 @@ fn act365f(start: NaiveDate, end: NaiveDate) -> f64 {
     days / 365.0
 }
 
+fn act364(start: NaiveDate, end: NaiveDate) -> f64 {
+    let days = (end - start).num_days() as f64;
+    days / 364.0
+}
+
+fn act36525(start: NaiveDate, end: NaiveDate) -> f64 {
+    let days = (end - start).num_days() as f64;
+    days / 365.25
+}
+
+fn nl365(start: NaiveDate, end: NaiveDate) -> f64 {
+    let leap_days = (start.year()..=end.year())
+        .filter_map(|year| NaiveDate::from_ymd_opt(year, 2, 29))
+        .filter(|dt| *dt > start && *dt <= end)
+        .count();
+    let days = (end - start).num_days() - leap_days as i64;
+    days as f64 / 365.0
+}
+
+/// Act/365L: the denominator is 366 if ``end`` falls in a leap year or, for annual
+/// (or less frequent) coupons, if 29 Feb falls in $(start, end]$. Without schedule
+/// context the frequency is unknown and the period end rule applies.
+fn act365l(start: NaiveDate, end: NaiveDate, annual: bool) -> f64 {
+    let leap = if annual {
+        next_leap_date(start, end).is_some()
+    } else {
+        end.leap_year()
+    };
+    let days = (end - start).num_days() as f64;
+    days / if leap { 366.0 } else { 365.0 }
+}
+
 fn act_act_isda(start: NaiveDate, end: NaiveDate) -> f64 {
     if start.year() == end.year() {
         return act_act_isda_sub(start, end);
     }
 
-    let mid = NaiveDate::from_ymd_opt(end.year(), 1, 1).unwrap();
-    act_act_isda_sub(start, mid) + act_act_isda_sub(mid, end)
+    // * split the period on every 1 Jan so each piece lies within a single year
+    let mut yf = 0.0;
+    let mut tmp = start;
+    for year in (start.year() + 1)..=end.year() {
+        let mid = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+        yf += act_act_isda_sub(tmp, mid);
+        tmp = mid;
+    }
+    yf + act_act_isda_sub(tmp, end)
 }
 
 fn act_act_isda_sub(start: NaiveDate, end: NaiveDate) -> f64 {
@@ -58,10 +411,94 @@ fn act_act_isda_sub(start: NaiveDate, end: NaiveDate) -> f64 {
     days / denom
 }
 
+fn act_act_afb(start: NaiveDate, end: NaiveDate) -> f64 {
+    let mut years = 0.0;
+    let mut tmp = end;
+    loop {
+        let mut prev = tmp - Months::new(12);
+        // * counting back a year from 28 Feb lands on 29 Feb in leap years
+        if prev.month() == 2 && prev.day() == 28 && prev.leap_year() {
+            prev = prev.succ_opt().unwrap();
+        }
+        if prev < start {
+            break;
+        }
+        years += 1.0;
+        tmp = prev;
+    }
+
+    let leap_day = NaiveDate::from_ymd_opt(start.year(), 2, 29)
+        .or_else(|| NaiveDate::from_ymd_opt(tmp.year(), 2, 29));
+    let denom = if leap_day.is_some_and(|dt| start <= dt && dt < tmp) {
+        366.0
+    } else {
+        365.0
+    };
+    years + (tmp - start).num_days() as f64 / denom
+}
+
+fn act_act_icma(period: &SchedulePeriod, schedule: &Schedule, until: NaiveDate) -> f64 {
+    let start = period.start;
+    // * schedules of day periods have no coupon frequency, so the period is its own
+    // * reference period of one year
+    let Some(freq) = schedule.period.frequency() else {
+        return icma_sub(start, until, start, start + Months::new(12), 1.0);
+    };
+
+    // * a truncated period accrues over the notional periods of the original one
+    let (ref_start, ref_end) = period.reference_period();
+    let roll_conv = schedule.roll_conv;
+    let step = schedule.period;
+    if roll_conv.prev(ref_end, step) == ref_start
+        && roll_conv.next(ref_start, step) == ref_end
+    {
+        if start == ref_start && until == ref_end {
+            return 1.0 / freq;
+        }
+        return icma_sub(start, until, ref_start, ref_end, freq);
+    }
+
+    // * an irregular first period is a front stub and its notional periods are rolled
+    // * backward from its end; any other irregular period is rolled forward from its
+    // * start (ICMA Rule 251.1 (iii))
+    let is_front = schedule.front_stub && schedule.periods.first() == Some(period);
+    let mut yf = 0.0;
+    if is_front {
+        let mut notional_end = ref_end;
+        while notional_end > ref_start {
+            let notional_start = roll_conv.prev(notional_end, step);
+            yf += icma_sub(start, until, notional_start, notional_end, freq);
+            notional_end = notional_start;
+        }
+    } else {
+        let mut notional_start = ref_start;
+        while notional_start < ref_end {
+            let notional_end = roll_conv.next(notional_start, step);
+            yf += icma_sub(start, until, notional_start, notional_end, freq);
+            notional_start = notional_end;
+        }
+    }
+    yf
+}
+
+/// Year fraction contributed by the notional period $[ref_start, ref_end)$ to the
+/// accrual period $[start, end)$; nothing if they do not overlap.
+fn icma_sub(
+    start: NaiveDate,
+    end: NaiveDate,
+    ref_start: NaiveDate,
+    ref_end: NaiveDate,
+    freq: f64,
+) -> f64 {
+    let days = (end.min(ref_end) - start.max(ref_start)).num_days().max(0) as f64;
+    let ref_days = (ref_end - ref_start).num_days() as f64;
+    days / (freq * ref_days)
+}
+
+/// Act/365 Actual is the per-year split defined for Act/Act ISDA, i.e. ISDA 2006
+/// Section 4.16(b).
 fn act365(start: NaiveDate, end: NaiveDate) -> f64 {
-    let next_leap = next_leap_date(start);
-    let days = (end - start).num_days() as f64;
-    days / if next_leap < end { 366.0 } else { 365.0 }
+    act_act_isda(start, end)
 }
 
 fn bus252(start: NaiveDate, end: NaiveDate, cal: &BusinessCalendar) -> f64 {
@@ -69,23 +506,117 @@ fn bus252(start: NaiveDate, end: NaiveDate, cal: &BusinessCalendar) -> f64 {
     days / 252.0
 }
 
-fn next_leap_date(dt: NaiveDate) -> NaiveDate {
-    for i in 0..8 {
-        let year = dt.year() + i;
-        let tmp = NaiveDate::from_ymd_opt(year, 2, 29);
-        if tmp.is_some_and(|d| d > dt) {
-            return tmp.unwrap();
+/// Bus/252 over many periods from one prefix sum of business days over the span of
+/// all of them, so the calendar is walked once rather than once per period.
+fn bus252_batch(bounds: &[(NaiveDate, NaiveDate)], cal: &BusinessCalendar) -> Vec<f64> {
+    let dates = bounds.iter().flat_map(|&(start, end)| [start, end]);
+    let (Some(lo), Some(hi)) = (dates.clone().min(), dates.max()) else {
+        return Vec::new();
+    };
+
+    // * prefix[i] is the number of business days in [lo, lo + i); jump from one
+    // * business day to the next and fill the days in between in bulk
+    let index = |dt: NaiveDate| (dt - lo).num_days() as usize;
+    let span = index(hi);
+    let mut prefix = Vec::with_capacity(span + 1);
+    let mut count = 0_i64;
+    let mut dt = cal.adjust(lo, BusdayConvention::Following);
+    while dt < hi {
+        prefix.resize(index(dt) + 1, count);
+        count += 1;
+        dt = cal.adjust(dt + Days::new(1), BusdayConvention::Following);
+    }
+    prefix.resize(span + 1, count);
+
+    bounds
+        .iter()
+        .map(|&(start, end)| (prefix[index(end)] - prefix[index(start)]) as f64 / 252.0)
+        .collect()
+}
+
+fn thirty360(start: NaiveDate, end: NaiveDate) -> f64 {
+    let mut d1 = start.day();
+    let mut d2 = end.day();
+
+    if is_last_day_of_feb(start) {
+        if is_last_day_of_feb(end) {
+            d2 = 30;
         }
+        d1 = 30;
+    }
+    if d2 == 31 && d1 >= 30 {
+        d2 = 30;
+    }
+    if d1 == 31 {
+        d1 = 30;
+    }
+
+    thirty360_sub(start, d1, end, d2)
+}
+
+fn thirty360e(start: NaiveDate, end: NaiveDate) -> f64 {
+    let d1 = start.day().min(30);
+    let d2 = end.day().min(30);
+    thirty360_sub(start, d1, end, d2)
+}
+
+fn thirty360e_isda(start: NaiveDate, end: NaiveDate, termination: NaiveDate) -> f64 {
+    let d1 = if is_last_day_of_month(start) {
+        30
+    } else {
+        start.day()
+    };
+    let d2 = if is_last_day_of_month(end) && !(end == termination && end.month() == 2) {
+        30
+    } else {
+        end.day()
+    };
+    thirty360_sub(start, d1, end, d2)
+}
+
+fn thirty360e_plus(start: NaiveDate, end: NaiveDate) -> f64 {
+    let d1 = start.day().min(30);
+    if end.day() == 31 {
+        // * roll D2 to the first day of the following month
+        let days = 360 * (end.year() - start.year())
+            + 30 * (end.month() as i32 + 1 - start.month() as i32)
+            + (1 - d1 as i32);
+        return days as f64 / 360.0;
     }
+    thirty360_sub(start, d1, end, end.day())
+}
+
+/// Compute $[360 (Y_2 - Y_1) + 30 (M_2 - M_1) + (D_2 - D_1)] / 360$ using the
+/// already-adjusted day numbers ``d1`` and ``d2``.
+fn thirty360_sub(start: NaiveDate, d1: u32, end: NaiveDate, d2: u32) -> f64 {
+    let days = 360 * (end.year() - start.year())
+        + 30 * (end.month() as i32 - start.month() as i32)
+        + (d2 as i32 - d1 as i32);
+    days as f64 / 360.0
+}
 
-    // ! this should never evaluate but satisfy thecompiler
-    dt
+fn is_last_day_of_month(dt: NaiveDate) -> bool {
+    dt.day() == dt.num_days_in_month() as u32
+}
+
+fn is_last_day_of_feb(dt: NaiveDate) -> bool {
+    dt.month() == 2 && is_last_day_of_month(dt)
+}
+
+/// Get the first 29 Feb in $(dt, until]$, if any; century years such as 2100 have
+/// none, so the gap between leap days can be eight years.
+fn next_leap_date(dt: NaiveDate, until: NaiveDate) -> Option<NaiveDate> {
+    (dt.year()..=until.year())
+        .filter_map(|year| NaiveDate::from_ymd_opt(year, 2, 29))
+        .find(|d| *d > dt && *d <= until)
 }
 
 #[cfg(test)]
 mod tests {
-    use bizdate::BusinessCalendar;
-    use chrono::NaiveDate;
+    use bizdate::{BusdayConvention, BusinessCalendar};
+    use chrono::{Days, NaiveDate};
+
+    use crate::{AccrualDates, Period, RollConvention, Schedule, SchedulingError};
 
     use super::Daycounts;
 
@@ -113,6 +644,795 @@ mod tests {
         let end = NaiveDate::parse_from_str("20260630", "%Y%m%d").unwrap();
         let cal = get_cal();
         let dc = Daycounts::Bus252(&cal);
-        assert_eq!(dc.year_fraction(start, end), 123.0 / 252.0)
+        assert_eq!(dc.try_year_fraction(start, end).unwrap(), 123.0 / 252.0)
+    }
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    // * ISDA 2006 Section 4.16 example periods; day counts per convention are
+    // * (30/360 US, 30E/360, 30E/360 ISDA, 30E+/360)
+    static THIRTY360_CASES: &[(&str, &str, [i32; 4])] = &[
+        ("2007-01-15", "2007-01-30", [15, 15, 15, 15]),
+        ("2007-01-15", "2007-02-15", [30, 30, 30, 30]),
+        ("2007-01-15", "2007-07-15", [180, 180, 180, 180]),
+        ("2007-09-30", "2008-03-31", [180, 180, 180, 181]),
+        ("2007-09-30", "2007-10-31", [30, 30, 30, 31]),
+        ("2007-09-30", "2008-09-30", [360, 360, 360, 360]),
+        ("2007-01-15", "2007-01-31", [16, 15, 15, 16]),
+        ("2007-01-31", "2007-02-28", [28, 28, 30, 28]),
+        ("2007-02-28", "2007-03-31", [30, 32, 30, 33]),
+        ("2006-08-31", "2007-02-28", [178, 178, 180, 178]),
+        ("2007-02-28", "2007-08-31", [180, 182, 180, 183]),
+        ("2007-02-14", "2007-02-28", [14, 14, 16, 14]),
+        ("2007-02-26", "2008-02-29", [363, 363, 364, 363]),
+        ("2008-02-29", "2009-02-28", [360, 359, 360, 359]),
+        ("2008-02-29", "2008-03-30", [30, 31, 30, 31]),
+        ("2008-02-29", "2008-03-31", [30, 31, 30, 32]),
+        ("2007-02-28", "2007-03-05", [5, 7, 5, 7]),
+        ("2007-10-31", "2007-11-28", [28, 28, 28, 28]),
+        ("2007-08-31", "2008-02-29", [179, 179, 180, 179]),
+        ("2008-02-29", "2008-08-31", [180, 181, 180, 182]),
+        ("2008-08-31", "2009-02-28", [178, 178, 180, 178]),
+        ("2009-02-28", "2009-08-31", [180, 182, 180, 183]),
+    ];
+
+    #[test]
+    fn test_thirty360() {
+        for (start, end, days) in THIRTY360_CASES {
+            let yf = Daycounts::Thirty360
+                .try_year_fraction(ymd(start), ymd(end))
+                .unwrap();
+            assert_eq!(yf, days[0] as f64 / 360.0, "{start} -> {end}");
+        }
+    }
+
+    #[test]
+    fn test_thirty360e() {
+        for (start, end, days) in THIRTY360_CASES {
+            let yf = Daycounts::Thirty360E
+                .try_year_fraction(ymd(start), ymd(end))
+                .unwrap();
+            assert_eq!(yf, days[1] as f64 / 360.0, "{start} -> {end}");
+        }
+    }
+
+    #[test]
+    fn test_thirty360e_isda() {
+        // * termination well after every case so the Feb exemption never applies
+        let dc = Daycounts::Thirty360EIsda(ymd("2099-12-31"));
+        for (start, end, days) in THIRTY360_CASES {
+            let yf = dc.try_year_fraction(ymd(start), ymd(end)).unwrap();
+            assert_eq!(yf, days[2] as f64 / 360.0, "{start} -> {end}");
+        }
+    }
+
+    #[test]
+    fn test_thirty360e_isda_termination() {
+        // * last day of Feb is not rolled to 30 when it is the termination date
+        let dc = Daycounts::Thirty360EIsda(ymd("2008-02-29"));
+        let yf = dc
+            .try_year_fraction(ymd("2007-08-31"), ymd("2008-02-29"))
+            .unwrap();
+        assert_eq!(yf, 179.0 / 360.0);
+
+        let dc = Daycounts::Thirty360EIsda(ymd("2009-02-28"));
+        let yf = dc
+            .try_year_fraction(ymd("2008-02-29"), ymd("2009-02-28"))
+            .unwrap();
+        assert_eq!(yf, 358.0 / 360.0);
+
+        // * only the final period of a schedule ends on the termination date
+        let sch = icma_schedule(
+            "2007-08-31",
+            None,
+            None,
+            "2009-02-28",
+            Period::Months(6),
+            true,
+        );
+        let expected = [180.0 / 360.0, 180.0 / 360.0, 178.0 / 360.0];
+        for (period, yf) in sch.periods.iter().zip(expected) {
+            assert_eq!(dc.period_year_fraction(period, &sch), yf);
+        }
+    }
+
+    #[test]
+    fn test_thirty360e_plus() {
+        for (start, end, days) in THIRTY360_CASES {
+            let yf = Daycounts::Thirty360EPlus
+                .try_year_fraction(ymd(start), ymd(end))
+                .unwrap();
+            assert_eq!(yf, days[3] as f64 / 360.0, "{start} -> {end}");
+        }
+    }
+
+    fn icma_schedule<'a>(
+        effective: &str,
+        front_stub: Option<&str>,
+        back_stub: Option<&str>,
+        termination: &str,
+        period: Period<'a>,
+        eom: bool,
+    ) -> Schedule<'a> {
+        let mut builder = Schedule::builder()
+            .effective(ymd(effective))
+            .termination(ymd(termination))
+            .period(period);
+        if let Some(dt) = front_stub {
+            builder = builder.first_regular(ymd(dt));
+        }
+        if let Some(dt) = back_stub {
+            builder = builder.last_regular(ymd(dt));
+        }
+        if eom {
+            builder = builder.roll_conv(RollConvention::Eom);
+        }
+        builder.build().unwrap()
+    }
+
+    fn assert_close(lhs: f64, rhs: f64) {
+        assert!((lhs - rhs).abs() < 1e-12, "{lhs} != {rhs}");
+    }
+
+    // * examples from the ISDA memo "EMU and Market Conventions: Recent Developments"
+
+    #[test]
+    fn test_act_act_icma_regular() {
+        let sch = icma_schedule(
+            "2003-11-01",
+            None,
+            None,
+            "2005-05-01",
+            Period::Months(6),
+            false,
+        );
+        let yf = Daycounts::ActActIcma.period_year_fraction(&sch.periods[0], &sch);
+        assert_eq!(yf, 0.5);
+    }
+
+    #[test]
+    fn test_act_act_icma_short_first() {
+        let sch = icma_schedule(
+            "1999-02-01",
+            Some("1999-07-01"),
+            None,
+            "2002-07-01",
+            Period::Years(1),
+            false,
+        );
+        let dc = Daycounts::ActActIcma;
+        assert_close(
+            dc.period_year_fraction(&sch.periods[0], &sch),
+            150.0 / 365.0,
+        );
+        assert_eq!(dc.period_year_fraction(&sch.periods[1], &sch), 1.0);
+    }
+
+    #[test]
+    fn test_act_act_icma_long_first() {
+        let sch = icma_schedule(
+            "2002-08-15",
+            Some("2003-07-15"),
+            None,
+            "2005-07-15",
+            Period::Months(6),
+            false,
+        );
+        let yf = Daycounts::ActActIcma.period_year_fraction(&sch.periods[0], &sch);
+        assert_close(yf, 153.0 / (2.0 * 184.0) + 181.0 / (2.0 * 181.0));
+    }
+
+    #[test]
+    fn test_act_act_icma_short_final() {
+        // * 2027-01-15 to 2027-04-10 is 85 days of the notional period to 2027-07-15
+        let sch = icma_schedule(
+            "2026-01-15",
+            None,
+            Some("2027-01-15"),
+            "2027-04-10",
+            Period::Months(6),
+            false,
+        );
+        let last = sch.periods.last().unwrap();
+        let yf = Daycounts::ActActIcma.period_year_fraction(last, &sch);
+        assert_close(yf, 85.0 / (2.0 * 181.0));
+    }
+
+    #[test]
+    fn test_act_act_icma_long_final_non_eom() {
+        // * 5 months on a quarterly schedule: 2000-01-30 to 2000-04-30 (91 days) and
+        // * 61 of the 91 days to 2000-07-30
+        let sch = icma_schedule(
+            "1999-10-30",
+            None,
+            Some("2000-01-30"),
+            "2000-06-30",
+            Period::Months(3),
+            false,
+        );
+        let yf = Daycounts::ActActIcma.period_year_fraction(&sch.periods[1], &sch);
+        assert_close(yf, 91.0 / (4.0 * 91.0) + 61.0 / (4.0 * 91.0));
+    }
+
+    #[test]
+    fn test_act_act_icma_long_final() {
+        let sch = icma_schedule(
+            "1999-05-31",
+            None,
+            Some("1999-11-30"),
+            "2000-04-30",
+            Period::Months(3),
+            true,
+        );
+        let last = sch.periods.last().unwrap();
+        let yf = Daycounts::ActActIcma.period_year_fraction(last, &sch);
+        assert_close(yf, 91.0 / (4.0 * 91.0) + 61.0 / (4.0 * 92.0));
+    }
+
+    #[test]
+    fn test_act_act_icma_standalone() {
+        let dc = Daycounts::ActActIcma;
+        let rslt = dc.try_year_fraction(ymd("2003-11-01"), ymd("2004-05-01"));
+        assert!(matches!(rslt, Err(SchedulingError::InvalidDaycount(_))));
+        assert_eq!(dc.inverse_year_fraction(ymd("2003-11-01"), 0.5), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Act/Act ICMA")]
+    #[allow(deprecated)]
+    fn test_act_act_icma_standalone_panics() {
+        Daycounts::ActActIcma.year_fraction(ymd("2003-11-01"), ymd("2004-05-01"));
+    }
+
+    #[test]
+    fn test_act365l_annual() {
+        let sch = icma_schedule(
+            "2007-12-28",
+            Some("2008-02-28"),
+            None,
+            "2010-02-28",
+            Period::Years(1),
+            false,
+        );
+        let dc = Daycounts::Act365L;
+        // * period ends in a leap year but does not contain 29 Feb
+        assert_eq!(dc.period_year_fraction(&sch.periods[0], &sch), 62.0 / 365.0);
+        // * 2008-02-28 -> 2009-02-28 contains 29 Feb
+        assert_eq!(
+            dc.period_year_fraction(&sch.periods[1], &sch),
+            366.0 / 366.0
+        );
+
+        let sch = icma_schedule(
+            "2007-12-28",
+            Some("2008-02-29"),
+            None,
+            "2009-02-28",
+            Period::Years(1),
+            false,
+        );
+        assert_eq!(dc.period_year_fraction(&sch.periods[0], &sch), 63.0 / 366.0);
+        // * 29 Feb on the start date is excluded
+        assert_eq!(
+            dc.period_year_fraction(&sch.periods[1], &sch),
+            365.0 / 365.0
+        );
+    }
+
+    #[test]
+    fn test_act365l_annual_century() {
+        // * 2100 is not a leap year, so 2096-02-29 to 2104-02-29 has no leap day and
+        // * only 2103-03-01 -> 2104-03-01 is over 366
+        let sch = icma_schedule(
+            "2096-03-01",
+            None,
+            None,
+            "2105-03-01",
+            Period::Years(1),
+            false,
+        );
+        let dc = Daycounts::Act365L;
+        for period in &sch.periods {
+            assert_eq!(dc.period_year_fraction(period, &sch), 1.0, "{period:?}");
+        }
+        assert_eq!(sch.periods[7].days(), 366);
+    }
+
+    #[test]
+    fn test_act365l_non_annual() {
+        let sch = icma_schedule(
+            "2007-11-28",
+            None,
+            None,
+            "2009-02-28",
+            Period::Months(3),
+            false,
+        );
+        let dc = Daycounts::Act365L;
+        let expected = [
+            92.0 / 366.0,
+            90.0 / 366.0,
+            92.0 / 366.0,
+            92.0 / 366.0,
+            92.0 / 365.0,
+        ];
+        for (period, yf) in sch.periods.iter().zip(expected) {
+            assert_eq!(dc.period_year_fraction(period, &sch), yf);
+        }
+    }
+
+    #[test]
+    fn test_act365l_standalone() {
+        let dc = Daycounts::Act365L;
+        assert_eq!(
+            dc.try_year_fraction(ymd("2007-12-28"), ymd("2008-02-28"))
+                .unwrap(),
+            62.0 / 366.0
+        );
+        assert_eq!(
+            dc.try_year_fraction(ymd("2008-02-28"), ymd("2009-02-28"))
+                .unwrap(),
+            366.0 / 365.0
+        );
+    }
+
+    #[test]
+    fn test_act_act_isda() {
+        let dc = Daycounts::ActActIsda;
+        let yf = dc
+            .try_year_fraction(ymd("2003-11-01"), ymd("2004-05-01"))
+            .unwrap();
+        assert_close(yf, 61.0 / 365.0 + 121.0 / 366.0);
+
+        // * multi-year periods are split on every year boundary
+        let yf = dc
+            .try_year_fraction(ymd("2019-12-15"), ymd("2022-03-15"))
+            .unwrap();
+        assert_close(yf, 17.0 / 365.0 + 2.0 + 73.0 / 365.0);
+    }
+
+    #[test]
+    fn test_act_act_afb() {
+        let dc = Daycounts::ActActAfb;
+        let cases = [
+            // * ISDA "EMU and Market Conventions" examples
+            ("2003-11-01", "2004-05-01", 182.0 / 366.0),
+            ("1999-02-01", "1999-07-01", 150.0 / 365.0),
+            ("2002-08-15", "2003-07-15", 334.0 / 365.0),
+            ("2000-01-30", "2000-06-30", 152.0 / 366.0),
+            ("1999-11-30", "2000-04-30", 152.0 / 366.0),
+            ("1994-02-10", "1997-06-30", 3.0 + 140.0 / 365.0),
+            // * residual spanning 29 Feb after counting back whole years
+            ("2003-12-01", "2006-03-01", 2.0 + 91.0 / 366.0),
+            // * periods ending on 28/29 Feb
+            ("2008-02-29", "2009-02-28", 1.0),
+            ("2004-02-29", "2008-02-29", 4.0),
+            ("2004-02-28", "2008-02-29", 4.0 + 1.0 / 365.0),
+            ("1999-02-28", "2004-02-28", 5.0),
+            ("2003-01-15", "2005-02-28", 2.0 + 44.0 / 365.0),
+        ];
+        for (start, end, yf) in cases {
+            assert_close(dc.try_year_fraction(ymd(start), ymd(end)).unwrap(), yf);
+        }
+    }
+
+    #[test]
+    fn test_act365() {
+        let dc = Daycounts::Act365;
+        let yf = dc
+            .try_year_fraction(ymd("2023-12-01"), ymd("2024-03-01"))
+            .unwrap();
+        assert_close(yf, 31.0 / 365.0 + 60.0 / 366.0);
+
+        let yf = dc
+            .try_year_fraction(ymd("2019-12-15"), ymd("2022-03-15"))
+            .unwrap();
+        assert_close(yf, 17.0 / 365.0 + 2.0 + 73.0 / 365.0);
+
+        let yf = dc
+            .try_year_fraction(ymd("2024-01-01"), ymd("2025-01-01"))
+            .unwrap();
+        assert_close(yf, 1.0);
+    }
+
+    #[test]
+    fn test_nl365() {
+        let dc = Daycounts::Nl365;
+        assert_eq!(
+            dc.try_year_fraction(ymd("2024-02-28"), ymd("2024-03-01"))
+                .unwrap(),
+            1.0 / 365.0
+        );
+        // * 29 Feb counts on the end date but not on the start date
+        assert_eq!(
+            dc.try_year_fraction(ymd("2024-02-01"), ymd("2024-02-29"))
+                .unwrap(),
+            27.0 / 365.0
+        );
+        assert_eq!(
+            dc.try_year_fraction(ymd("2024-02-29"), ymd("2024-03-01"))
+                .unwrap(),
+            1.0 / 365.0
+        );
+
+        // * 821 actual days spanning 29 Feb 2020
+        let yf = dc
+            .try_year_fraction(ymd("2019-12-15"), ymd("2022-03-15"))
+            .unwrap();
+        assert_eq!(yf, 820.0 / 365.0);
+
+        // * 1827 actual days spanning 29 Feb 2024 and 29 Feb 2028
+        let yf = dc
+            .try_year_fraction(ymd("2023-06-30"), ymd("2028-06-30"))
+            .unwrap();
+        assert_eq!(yf, 5.0);
+    }
+
+    #[test]
+    fn test_act364() {
+        let dc = Daycounts::Act364;
+        assert_eq!(
+            dc.try_year_fraction(ymd("2024-01-01"), ymd("2024-12-30"))
+                .unwrap(),
+            364.0 / 364.0
+        );
+        let yf = dc
+            .try_year_fraction(ymd("2019-12-15"), ymd("2022-03-15"))
+            .unwrap();
+        assert_eq!(yf, 821.0 / 364.0);
+    }
+
+    #[test]
+    fn test_act36525() {
+        let dc = Daycounts::Act36525;
+        let yf = dc
+            .try_year_fraction(ymd("2020-01-01"), ymd("2024-01-01"))
+            .unwrap();
+        assert_eq!(yf, 1461.0 / 365.25);
+        let yf = dc
+            .try_year_fraction(ymd("2024-02-28"), ymd("2024-03-01"))
+            .unwrap();
+        assert_eq!(yf, 2.0 / 365.25);
+    }
+
+    #[test]
+    fn test_inverse_year_fraction() {
+        let start = ymd("2024-01-15");
+        assert_eq!(
+            Daycounts::Act360.inverse_year_fraction(start, 0.5),
+            Some(ymd("2024-07-13"))
+        );
+        assert_eq!(
+            Daycounts::Act365F.inverse_year_fraction(start, 1.0),
+            Some(ymd("2025-01-14"))
+        );
+        assert_eq!(
+            Daycounts::Act360.inverse_year_fraction(start, 0.0),
+            Some(start)
+        );
+        assert_eq!(
+            Daycounts::Act360.inverse_year_fraction(start, -1.0),
+            Some(start)
+        );
+        assert_eq!(
+            Daycounts::Act360.inverse_year_fraction(start, f64::NAN),
+            None
+        );
+        assert_eq!(Daycounts::Act360.inverse_year_fraction(start, 1e6), None);
+    }
+
+    #[test]
+    fn test_inverse_year_fraction_round_trip() {
+        let start = ymd("2023-11-30");
+        let dcs = [
+            Daycounts::Act360,
+            Daycounts::Act365F,
+            Daycounts::Act365,
+            Daycounts::ActActIsda,
+            Daycounts::ActActAfb,
+        ];
+        for dc in dcs {
+            for days in [1, 45, 91, 183, 366, 1000, 3653] {
+                let end = start + Days::new(days);
+                let yf = dc.try_year_fraction(start, end).unwrap();
+                assert_eq!(dc.inverse_year_fraction(start, yf), Some(end), "{dc}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_year_fraction_thirty360() {
+        let dc = Daycounts::Thirty360;
+        // * 30 and 31 Jul both give 180 days; the earliest is returned
+        let rslt = dc.inverse_year_fraction(ymd("2024-01-31"), 0.5);
+        assert_eq!(rslt, Some(ymd("2024-07-30")));
+
+        // * 28 Feb gives 28 days and 1 Mar jumps to 31 days
+        let rslt = dc.inverse_year_fraction(ymd("2023-01-31"), 30.0 / 360.0);
+        assert_eq!(rslt, Some(ymd("2023-03-01")));
+    }
+
+    #[test]
+    fn test_inverse_year_fraction_non_monotone() {
+        // * 2920 / 365 on 30 Dec 2007 drops to 2922 / 366 on 1 Jan 2008
+        let dc = Daycounts::Act365L;
+        let rslt = dc.inverse_year_fraction(ymd("2000-01-01"), 8.0);
+        assert_eq!(rslt, Some(ymd("2007-12-30")));
+    }
+
+    #[test]
+    fn test_inverse_year_fraction_bus252() {
+        let cal = get_cal();
+        let dc = Daycounts::Bus252(&cal);
+        let start = ymd("2026-01-01");
+        let rslt = dc.inverse_year_fraction(start, 123.0 / 252.0);
+        assert_eq!(rslt, Some(ymd("2026-06-30")));
+
+        // * Fri 2 Jan is the first business day; the count already reaches 1 on Sat
+        let rslt = dc.inverse_year_fraction(start, 1.0 / 252.0);
+        assert_eq!(rslt, Some(ymd("2026-01-03")));
+    }
+
+    #[test]
+    fn test_inverse_year_fraction_nl365() {
+        // * 28 and 29 Feb give the same year fraction; the earliest is returned
+        let dc = Daycounts::Nl365;
+        let rslt = dc.inverse_year_fraction(ymd("2023-11-30"), 90.0 / 365.0);
+        assert_eq!(rslt, Some(ymd("2024-02-28")));
+    }
+
+    #[test]
+    fn test_schedule_year_fractions() {
+        // * quarterly periods over 2026, with 2026-01-01 adjusting to 2026-01-02;
+        // * 61, 63, 64 and 61 business days net of the holidays in each quarter
+        let cal = get_cal();
+        let sch = Schedule::builder()
+            .effective(ymd("2026-01-01"))
+            .termination(ymd("2026-12-31"))
+            .period(Period::Months(3))
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::Following)
+            .build()
+            .unwrap();
+        let cases = [
+            (
+                Daycounts::Act360,
+                AccrualDates::Unadjusted,
+                [90, 91, 92, 91],
+                360.0,
+            ),
+            (
+                Daycounts::Act360,
+                AccrualDates::Adjusted,
+                [89, 91, 92, 91],
+                360.0,
+            ),
+            (
+                Daycounts::Bus252(&cal),
+                AccrualDates::Unadjusted,
+                [61, 63, 64, 61],
+                252.0,
+            ),
+            (
+                Daycounts::Bus252(&cal),
+                AccrualDates::Adjusted,
+                [61, 63, 64, 61],
+                252.0,
+            ),
+        ];
+        for (dc, dates, days, basis) in cases {
+            let expected = days.map(|d| d as f64 / basis);
+            assert_eq!(dc.schedule_year_fractions(&sch, dates), expected, "{dc}");
+        }
+
+        // * the batched Bus/252 agrees with counting each period on its own, here
+        // * monthly periods whose adjusted ends fall on holidays and weekends
+        let sch = Schedule::builder()
+            .effective(ymd("2025-12-19"))
+            .termination(ymd("2026-12-19"))
+            .period(Period::Months(1))
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::ModifiedFollowing)
+            .build()
+            .unwrap();
+        let dc = Daycounts::Bus252(&cal);
+        for dates in [AccrualDates::Unadjusted, AccrualDates::Adjusted] {
+            let expected: Vec<_> = sch
+                .periods
+                .iter()
+                .map(|p| {
+                    let (start, end) = p.accrual_dates(dates);
+                    cal.busday_count(start, end) as f64 / 252.0
+                })
+                .collect();
+            assert_eq!(dc.schedule_year_fractions(&sch, dates), expected);
+        }
+    }
+
+    #[test]
+    fn test_accrued_year_fraction() {
+        // * a long front stub 2026-01-01 to 2026-07-15 over the notional periods
+        // * 2025-07-15 to 2026-01-15 (14 of 184 days) and 2026-01-15 to 2026-07-15
+        let sch = icma_schedule(
+            "2026-01-01",
+            Some("2026-07-15"),
+            None,
+            "2027-07-15",
+            Period::Months(6),
+            false,
+        );
+        let dc = Daycounts::ActActIcma;
+        let (stub, regular) = (&sch.periods[0], &sch.periods[1]);
+        let dates = AccrualDates::Unadjusted;
+
+        let rslt = dc.accrued_year_fraction(stub, &sch, dates, ymd("2026-01-10"));
+        assert_close(rslt, 9.0 / (2.0 * 184.0));
+        let rslt = dc.accrued_year_fraction(stub, &sch, dates, ymd("2026-02-15"));
+        assert_close(rslt, 14.0 / (2.0 * 184.0) + 31.0 / (2.0 * 181.0));
+
+        // * 2026-07-15 to 2027-01-15 is 184 days
+        let rslt = dc.accrued_year_fraction(regular, &sch, dates, ymd("2026-08-15"));
+        assert_close(rslt, 31.0 / (2.0 * 184.0));
+
+        // * accruing to the period end is the full period year fraction
+        for dc in [
+            Daycounts::Act360,
+            Daycounts::ActActIcma,
+            Daycounts::Thirty360EIsda(ymd("2026-12-31")),
+        ] {
+            for p in &sch.periods {
+                let rslt = dc.accrued_year_fraction(p, &sch, dates, p.end);
+                assert_eq!(rslt, dc.period_year_fraction(p, &sch), "{dc}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sliced_icma() {
+        // * truncating the long front stub on 2026-02-15 accrues the rest of it over
+        // * the original notional periods
+        let sch = icma_schedule(
+            "2026-01-01",
+            Some("2026-07-15"),
+            None,
+            "2027-07-15",
+            Period::Months(6),
+            false,
+        );
+        let dc = Daycounts::ActActIcma;
+        let truncated = sch.truncate(ymd("2026-02-15")).unwrap();
+        assert_close(
+            dc.period_year_fraction(&truncated.periods[0], &truncated),
+            150.0 / (2.0 * 181.0),
+        );
+
+        // * truncating a regular period: 2026-08-15 to 2027-01-15 is 153 of 184 days
+        let truncated = sch.truncate(ymd("2026-08-15")).unwrap();
+        assert_close(
+            dc.period_year_fraction(&truncated.periods[0], &truncated),
+            153.0 / (2.0 * 184.0),
+        );
+
+        // * a back stub left as the only period still rolls forward from its start:
+        // * 2027-01-15 to 2027-04-10 is 85 days of the notional 181 to 2027-07-15
+        let sch = icma_schedule(
+            "2026-01-15",
+            None,
+            Some("2027-01-15"),
+            "2027-04-10",
+            Period::Months(6),
+            false,
+        );
+        let stub = sch.after(ymd("2027-01-15")).unwrap();
+        assert_eq!(stub.periods.len(), 1);
+        assert_close(
+            dc.period_year_fraction(&stub.periods[0], &stub),
+            85.0 / (2.0 * 181.0),
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let cal = get_cal();
+        let termination = ymd("2030-02-28");
+        let dcs = [
+            Daycounts::Act360,
+            Daycounts::Act365,
+            Daycounts::Act365F,
+            Daycounts::Nl365,
+            Daycounts::Act364,
+            Daycounts::Act36525,
+            Daycounts::ActActIsda,
+            Daycounts::ActActAfb,
+            Daycounts::ActActIcma,
+            Daycounts::Act365L,
+            Daycounts::Bus252(&cal),
+            Daycounts::Thirty360,
+            Daycounts::Thirty360E,
+            Daycounts::Thirty360EIsda(termination),
+            Daycounts::Thirty360EPlus,
+        ];
+        for dc in dcs {
+            let name = dc.to_string();
+            let rslt = Daycounts::parse_with(&name, |_| Some(&cal), Some(termination));
+            let parsed = rslt.unwrap();
+            assert_eq!(parsed.to_string(), name);
+            match (dc, parsed) {
+                (Daycounts::Bus252(a), Daycounts::Bus252(b)) => {
+                    assert!(std::ptr::eq(a, b))
+                }
+                (Daycounts::Thirty360EIsda(a), Daycounts::Thirty360EIsda(b)) => {
+                    assert_eq!(a, b)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_aliases() {
+        let cases = [
+            ("A360", "ACT/360"),
+            ("act/360", "ACT/360"),
+            ("ACT/365F", "ACT/365.FIXED"),
+            (" Actual/365 Fixed ", "ACT/365.FIXED"),
+            ("ACT/ACT", "ACT/ACT.ISDA"),
+            ("ACT/ACT.ISMA", "ACT/ACT.ICMA"),
+            ("30U/360", "30/360"),
+            ("EUROBOND BASIS", "30E/360"),
+            ("30E/360 ISDA", "30E/360.ISDA"),
+            ("German", "30E/360.ISDA"),
+        ];
+        for (alias, name) in cases {
+            let dc = Daycounts::parse_with(alias, |_| None, Some(ymd("2030-02-28")));
+            let dc = dc.unwrap();
+            assert_eq!(dc.to_string(), name);
+        }
+    }
+
+    #[test]
+    fn test_parse_bus252() {
+        let cal = get_cal();
+        let dc = Daycounts::parse_with("BUS/252", |_| Some(&cal), None).unwrap();
+        assert_eq!(dc.to_string(), "BUS/252");
+
+        let lookup = |code: Option<&str>| (code == Some("BRSP")).then_some(&cal);
+        let dc = Daycounts::parse_with("BUS/252:BRSP", lookup, None);
+        assert!(matches!(dc, Ok(Daycounts::Bus252(_))));
+
+        let rslt = Daycounts::parse_with("BUS/252:XXXX", lookup, None);
+        assert!(matches!(rslt, Err(SchedulingError::ParseErr(_))));
+
+        // * no calendar available through FromStr
+        assert!("BUS/252".parse::<Daycounts>().is_err());
+    }
+
+    #[test]
+    fn test_parse_err() {
+        let rslt = "ACT/999".parse::<Daycounts>();
+        let Err(SchedulingError::ParseErr(msg)) = rslt else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(msg, "Unknown day count convention ACT/999");
+
+        let rslt = "ACT/360:BRSP".parse::<Daycounts>();
+        assert!(matches!(rslt, Err(SchedulingError::ParseErr(_))));
+    }
+
+    #[test]
+    fn test_parse_thirty360e_isda() {
+        let termination = Some(ymd("2030-02-28"));
+        let dc = Daycounts::parse_with("30E/360.ISDA", |_| None, termination);
+        assert!(
+            matches!(dc, Ok(Daycounts::Thirty360EIsda(dt)) if Some(dt) == termination)
+        );
+
+        // * no termination date available through FromStr, and no qualifier
+        assert!("30E/360.ISDA".parse::<Daycounts>().is_err());
+        let rslt =
+            Daycounts::parse_with("30E/360.ISDA:2030-02-28", |_| None, termination);
+        assert!(matches!(rslt, Err(SchedulingError::ParseErr(_))));
     }
 }
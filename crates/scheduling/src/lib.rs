@@ -1,15 +1,49 @@
 pub mod adjuster;
+pub mod amortization;
 pub mod daycounts;
+pub mod observation;
+pub mod optionality;
 pub mod period;
+pub mod reset_period;
 pub mod roll_conv;
 pub mod schedule;
+pub mod schedule_builder;
 pub mod schedule_period;
 
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SchedulingError {
     ParseErr(String),
+    InvalidSchedule(String),
+    InvalidDaycount(String),
+}
+
+impl fmt::Display for SchedulingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseErr(msg) => write!(f, "parse error: {msg}"),
+            Self::InvalidSchedule(msg) => write!(f, "invalid schedule: {msg}"),
+            Self::InvalidDaycount(msg) => write!(f, "invalid day count: {msg}"),
+        }
+    }
 }
 
+impl std::error::Error for SchedulingError {}
+
+pub use amortization::Amortization;
 pub use daycounts::Daycounts;
-pub use period::Period;
+pub use observation::{
+    ObservationDate, ObservationMethod, ObservationSchedule, OvernightObservation,
+};
+pub use optionality::{
+    Exercise, ExerciseWindow, OptionKind, OptionSchedule, OptionScheduleBuilder, Strike,
+};
+pub use period::{Offset, Period};
+pub use reset_period::ResetPeriod;
 pub use roll_conv::{Roll, RollConvention};
-pub use schedule_period::SchedulePeriod;
+pub use schedule::Schedule;
+pub use schedule_builder::{
+    GenerationDirection, PaymentRelativeTo, RelativeTo, ScheduleBuilder, StubConvention,
+};
+pub use schedule_period::{AccrualDates, SchedulePeriod};
@@ -0,0 +1,293 @@
+use bizdate::{BusdayConvention, BusinessCalendar};
+use chrono::NaiveDate;
+
+use crate::SchedulePeriod;
+
+/// How overnight rates are observed relative to the interest period, per the ISDA
+/// 2021 definitions.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ObservationMethod {
+    /// Rates and weights both come from the interest period
+    #[default]
+    InArrears,
+    /// Rates are observed the given number of business days earlier; weights still
+    /// come from the interest period
+    Lookback(u32),
+    /// Both rates and weights come from an observation period shifted the given
+    /// number of business days earlier
+    ObservationShift(u32),
+}
+
+/// A single day of an overnight observation schedule.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ObservationDate {
+    pub(crate) date: NaiveDate,
+    pub(crate) rate_date: NaiveDate,
+    pub(crate) weight: i64,
+}
+
+impl ObservationDate {
+    /// Get the business day the weight applies to, in the interest period or, under an
+    /// observation shift, in the observation period.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Get the date the overnight rate is observed for.
+    pub fn rate_date(&self) -> NaiveDate {
+        self.rate_date
+    }
+
+    /// Get the number of calendar days the rate applies for, i.e. the days to the next
+    /// business day.
+    pub fn weight(&self) -> i64 {
+        self.weight
+    }
+}
+
+/// Daily observations of an overnight rate over a single accrual period.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObservationSchedule {
+    pub(crate) dates: Vec<ObservationDate>,
+    pub(crate) pay: NaiveDate,
+}
+
+impl ObservationSchedule {
+    pub fn dates(&self) -> &[ObservationDate] {
+        &self.dates
+    }
+
+    /// Get the payment date after any payment delay.
+    pub fn pay(&self) -> NaiveDate {
+        self.pay
+    }
+
+    /// Get the total number of calendar days observed, i.e. the sum of the weights.
+    pub fn days(&self) -> i64 {
+        self.dates.iter().map(|d| d.weight).sum()
+    }
+}
+
+/// Generator of overnight observation schedules for compounded or averaged rates,
+/// e.g. SOFR, SONIA or €STR legs.
+#[derive(Clone, Copy)]
+pub struct OvernightObservation<'a> {
+    cal: &'a BusinessCalendar,
+    method: ObservationMethod,
+    lockout: u32,
+    payment_delay: Option<(u32, &'a BusinessCalendar)>,
+}
+
+impl<'a> OvernightObservation<'a> {
+    /// Observe rates in arrears on the fixing calendar ``cal``.
+    pub fn new(cal: &'a BusinessCalendar) -> Self {
+        Self {
+            cal,
+            method: ObservationMethod::default(),
+            lockout: 0,
+            payment_delay: None,
+        }
+    }
+
+    pub fn method(mut self, method: ObservationMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Set the rate cut-off: the rates of the final ``days`` business days are
+    /// replaced by the rate observed on the business day before them.
+    pub fn lockout(mut self, days: u32) -> Self {
+        self.lockout = days;
+        self
+    }
+
+    /// Set the number of business days on the payment calendar ``cal`` after the
+    /// adjusted period end the payment is made.
+    pub fn payment_delay(mut self, days: u32, cal: &'a BusinessCalendar) -> Self {
+        self.payment_delay = Some((days, cal));
+        self
+    }
+
+    /// Generate the daily observations over the adjusted dates of ``period``.
+    pub fn generate(&self, period: &SchedulePeriod) -> ObservationSchedule {
+        let (start, end) = (period.start_adj, period.end_adj);
+        let shift =
+            |dt, days| self.cal.sub_busdays(dt, days, BusdayConvention::Following);
+
+        let mut dates: Vec<_> = match self.method {
+            ObservationMethod::InArrears => self.business_days(start, end),
+            ObservationMethod::Lookback(days) => self
+                .business_days(start, end)
+                .into_iter()
+                .map(|d| ObservationDate {
+                    rate_date: shift(d.date, days),
+                    ..d
+                })
+                .collect(),
+            ObservationMethod::ObservationShift(days) => {
+                self.business_days(shift(start, days), shift(end, days))
+            }
+        };
+
+        let lockout = self.lockout as usize;
+        if lockout > 0 && !dates.is_empty() {
+            let locked = dates.len().saturating_sub(lockout + 1);
+            let rate_date = dates[locked].rate_date;
+            for d in dates.iter_mut().skip(locked + 1) {
+                d.rate_date = rate_date;
+            }
+        }
+
+        let pay = self.payment_delay.map_or(end, |(days, cal)| {
+            cal.add_busdays(end, days, BusdayConvention::Following)
+        });
+        ObservationSchedule { dates, pay }
+    }
+
+    /// Get the business days in [start, end), each weighted by the calendar days to
+    /// the next business day (or ``end``).
+    fn business_days(&self, start: NaiveDate, end: NaiveDate) -> Vec<ObservationDate> {
+        let mut dates = Vec::new();
+        let mut dt = self.cal.adjust(start, BusdayConvention::Following);
+        while dt < end {
+            let next = self.cal.add_busdays(dt, 1, BusdayConvention::Following);
+            dates.push(ObservationDate {
+                date: dt,
+                rate_date: dt,
+                weight: (next.min(end) - dt).num_days(),
+            });
+            dt = next;
+        }
+        dates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bizdate::BusinessCalendar;
+    use chrono::NaiveDate;
+
+    use super::{ObservationMethod, OvernightObservation};
+    use crate::SchedulePeriod;
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn period() -> SchedulePeriod {
+        let (start, end) = (ymd("2026-03-02"), ymd("2026-03-09"));
+        SchedulePeriod::new(start, end, start, end)
+    }
+
+    fn rate_dates(obs: &OvernightObservation) -> Vec<NaiveDate> {
+        obs.generate(&period())
+            .dates
+            .iter()
+            .map(|d| d.rate_date)
+            .collect()
+    }
+
+    fn weights(obs: &OvernightObservation) -> Vec<i64> {
+        obs.generate(&period())
+            .dates
+            .iter()
+            .map(|d| d.weight)
+            .collect()
+    }
+
+    #[test]
+    fn test_in_arrears() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let obs = OvernightObservation::new(&cal);
+        let sch = obs.generate(&period());
+        let expected = [
+            "2026-03-02",
+            "2026-03-03",
+            "2026-03-04",
+            "2026-03-05",
+            "2026-03-06",
+        ];
+        assert_eq!(rate_dates(&obs), expected.map(ymd));
+        assert_eq!(weights(&obs), [1, 1, 1, 1, 3]);
+        assert_eq!(sch.days(), 7);
+        assert_eq!(sch.pay, ymd("2026-03-09"));
+
+        // * a holiday rolls its weight into the business day before it
+        let cal = BusinessCalendar::new(Some(vec![ymd("2026-03-04")]), "1111100");
+        let obs = OvernightObservation::new(&cal);
+        assert_eq!(weights(&obs), [1, 2, 1, 3]);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let obs =
+            OvernightObservation::new(&cal).method(ObservationMethod::Lookback(2));
+        let expected = [
+            "2026-02-26",
+            "2026-02-27",
+            "2026-03-02",
+            "2026-03-03",
+            "2026-03-04",
+        ];
+        assert_eq!(rate_dates(&obs), expected.map(ymd));
+        // * weights still come from the interest period
+        assert_eq!(weights(&obs), [1, 1, 1, 1, 3]);
+        let dates: Vec<_> = obs
+            .generate(&period())
+            .dates
+            .iter()
+            .map(|d| d.date)
+            .collect();
+        assert_eq!(dates[0], ymd("2026-03-02"));
+    }
+
+    #[test]
+    fn test_observation_shift() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let obs = OvernightObservation::new(&cal)
+            .method(ObservationMethod::ObservationShift(2));
+        let expected = [
+            "2026-02-26",
+            "2026-02-27",
+            "2026-03-02",
+            "2026-03-03",
+            "2026-03-04",
+        ];
+        assert_eq!(rate_dates(&obs), expected.map(ymd));
+        // * weights come from the shifted observation period
+        assert_eq!(weights(&obs), [1, 3, 1, 1, 1]);
+        assert_eq!(obs.generate(&period()).days(), 7);
+    }
+
+    #[test]
+    fn test_lockout() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let obs = OvernightObservation::new(&cal).lockout(2);
+        let expected = [
+            "2026-03-02",
+            "2026-03-03",
+            "2026-03-04",
+            "2026-03-04",
+            "2026-03-04",
+        ];
+        assert_eq!(rate_dates(&obs), expected.map(ymd));
+        assert_eq!(weights(&obs), [1, 1, 1, 1, 3]);
+
+        // * a lockout longer than the period locks everything to the first rate
+        let obs = OvernightObservation::new(&cal).lockout(10);
+        assert!(rate_dates(&obs).iter().all(|&dt| dt == ymd("2026-03-02")));
+    }
+
+    #[test]
+    fn test_payment_delay() {
+        // * the delay counts on the payment calendar, not the fixing calendar
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let payment = BusinessCalendar::new(Some(vec![ymd("2026-03-10")]), "1111100");
+        let obs = OvernightObservation::new(&cal).payment_delay(2, &payment);
+        assert_eq!(obs.generate(&period()).pay, ymd("2026-03-12"));
+
+        let obs = OvernightObservation::new(&payment).payment_delay(2, &cal);
+        assert_eq!(obs.generate(&period()).pay, ymd("2026-03-11"));
+    }
+}
@@ -0,0 +1,425 @@
+use bizdate::{BusdayConvention, BusinessCalendar};
+use chrono::NaiveDate;
+
+use crate::schedule_builder::invalid;
+use crate::{Schedule, SchedulingError};
+
+/// Who holds the option: the issuer (call) or the holder (put).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OptionKind {
+    Call,
+    Put,
+}
+
+/// When the option can be exercised.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Exercise {
+    /// On a single date
+    European(NaiveDate),
+    /// On every coupon date from ``first`` on, excluding maturity; ``first`` is
+    /// compared with the unadjusted coupon dates, exercise is on the adjusted ones
+    Bermudan { first: NaiveDate },
+    /// On any day in [start, end]
+    American { start: NaiveDate, end: NaiveDate },
+}
+
+/// Price the option is exercised at, e.g. par or a declining call schedule.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Strike {
+    Price(f64),
+    /// Prices effective from each date on, in ascending date order
+    Schedule(Vec<(NaiveDate, f64)>),
+}
+
+impl Strike {
+    /// Get the price effective on ``dt``, if any.
+    pub fn price(&self, dt: NaiveDate) -> Option<f64> {
+        match self {
+            Self::Price(price) => Some(*price),
+            Self::Schedule(steps) => steps
+                .iter()
+                .take_while(|(start, _)| *start <= dt)
+                .last()
+                .map(|(_, price)| *price),
+        }
+    }
+}
+
+/// A window the option can be exercised in at a single strike, with the date notice
+/// of exercise must be given by.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExerciseWindow {
+    pub(crate) start: NaiveDate,
+    pub(crate) end: NaiveDate,
+    pub(crate) notice: NaiveDate,
+    pub(crate) strike: f64,
+}
+
+impl ExerciseWindow {
+    /// Get the first exercise date of the window.
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Get the last exercise date of the window; equal to the start for European and
+    /// Bermudan exercise.
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Get the date notice must be given by to exercise at the start of the window.
+    pub fn notice(&self) -> NaiveDate {
+        self.notice
+    }
+
+    pub fn strike(&self) -> f64 {
+        self.strike
+    }
+
+    /// Check if ``dt`` falls within the window, i.e. $dt \in [start, end]$
+    pub fn contains(&self, dt: NaiveDate) -> bool {
+        dt >= self.start && dt <= self.end
+    }
+}
+
+/// Schedule of call or put exercise windows on a bond.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionSchedule {
+    pub(crate) kind: OptionKind,
+    pub(crate) windows: Vec<ExerciseWindow>,
+}
+
+impl OptionSchedule {
+    pub fn builder<'a>(
+        kind: OptionKind,
+        exercise: Exercise,
+    ) -> OptionScheduleBuilder<'a> {
+        OptionScheduleBuilder::new(kind, exercise)
+    }
+
+    pub fn kind(&self) -> OptionKind {
+        self.kind
+    }
+
+    pub fn windows(&self) -> &[ExerciseWindow] {
+        &self.windows
+    }
+
+    /// Get the window ``dt`` can be exercised in, if any.
+    pub fn window_on(&self, dt: NaiveDate) -> Option<&ExerciseWindow> {
+        self.windows.iter().find(|w| w.contains(dt))
+    }
+}
+
+/// Builder for an [`OptionSchedule`] relative to a coupon [`Schedule`].
+///
+/// The strike defaults to par (100). Notice dates are the given number of business
+/// days before each exercise date; a notice calendar is required for any notice
+/// period.
+#[derive(Clone)]
+pub struct OptionScheduleBuilder<'a> {
+    kind: OptionKind,
+    exercise: Exercise,
+    strike: Strike,
+    notice_days: u32,
+    notice_cal: Option<&'a BusinessCalendar>,
+}
+
+impl<'a> OptionScheduleBuilder<'a> {
+    pub fn new(kind: OptionKind, exercise: Exercise) -> Self {
+        Self {
+            kind,
+            exercise,
+            strike: Strike::Price(100.0),
+            notice_days: 0,
+            notice_cal: None,
+        }
+    }
+
+    pub fn strike(mut self, strike: Strike) -> Self {
+        self.strike = strike;
+        self
+    }
+
+    /// Set the number of business days before exercise notice must be given.
+    pub fn notice_days(mut self, days: u32) -> Self {
+        self.notice_days = days;
+        self
+    }
+
+    /// Set the business calendar notice days are counted on.
+    pub fn notice_calendar(mut self, cal: &'a BusinessCalendar) -> Self {
+        self.notice_cal = Some(cal);
+        self
+    }
+
+    /// Validate the inputs and generate the exercise windows over ``schedule``.
+    pub fn build(
+        &self,
+        schedule: &Schedule,
+    ) -> Result<OptionSchedule, SchedulingError> {
+        let (Some(first), Some(last)) = (schedule.first(), schedule.last()) else {
+            return Err(invalid("coupon schedule has no periods"));
+        };
+        let (effective, maturity) = (first.start_adj, last.end_adj);
+        if self.notice_days > 0 && self.notice_cal.is_none() {
+            return Err(invalid("notice period given without a calendar"));
+        }
+        if let Strike::Schedule(steps) = &self.strike
+            && (steps.is_empty() || steps.windows(2).any(|w| w[0].0 >= w[1].0))
+        {
+            return Err(invalid("strike schedule must be non-empty and ascending"));
+        }
+
+        let in_schedule = |dt: NaiveDate| dt > effective && dt <= maturity;
+        let dates: Vec<(NaiveDate, NaiveDate)> = match self.exercise {
+            Exercise::European(dt) if in_schedule(dt) => vec![(dt, dt)],
+            Exercise::Bermudan { first: dt } if dt > first.start && dt <= last.end => {
+                schedule
+                    .iter()
+                    .filter(|p| p.end >= dt && p.end < last.end)
+                    .map(|p| (p.end_adj, p.end_adj))
+                    .collect()
+            }
+            Exercise::American { start, end }
+                if start < end && in_schedule(start) && in_schedule(end) =>
+            {
+                self.strike_steps(start, end)
+            }
+            exercise => {
+                return Err(invalid(format!(
+                    "{exercise:?} must fall in ({effective}, {maturity}]"
+                )));
+            }
+        };
+
+        let windows = dates
+            .into_iter()
+            .map(|(start, end)| {
+                let strike = self.strike.price(start).ok_or_else(|| {
+                    invalid(format!("no strike price effective on {start}"))
+                })?;
+                let notice = match self.notice_cal {
+                    Some(cal) if self.notice_days > 0 => cal.sub_busdays(
+                        start,
+                        self.notice_days,
+                        BusdayConvention::Following,
+                    ),
+                    _ => start,
+                };
+                Ok(ExerciseWindow {
+                    start,
+                    end,
+                    notice,
+                    strike,
+                })
+            })
+            .collect::<Result<Vec<_>, SchedulingError>>()?;
+
+        if windows.is_empty() {
+            return Err(invalid("no exercise dates fall in the coupon schedule"));
+        }
+        Ok(OptionSchedule {
+            kind: self.kind,
+            windows,
+        })
+    }
+
+    /// Split [start, end] into windows of constant strike.
+    fn strike_steps(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut bounds = vec![start];
+        if let Strike::Schedule(steps) = &self.strike {
+            bounds.extend(
+                steps
+                    .iter()
+                    .map(|s| s.0)
+                    .filter(|dt| *dt > start && *dt <= end),
+            );
+        }
+        let mut windows: Vec<_> = bounds
+            .windows(2)
+            .map(|w| (w[0], w[1].pred_opt().unwrap()))
+            .collect();
+        windows.push((bounds[bounds.len() - 1], end));
+        windows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bizdate::{BusdayConvention, BusinessCalendar};
+    use chrono::NaiveDate;
+
+    use super::{Exercise, OptionKind, OptionSchedule, OptionScheduleBuilder, Strike};
+    use crate::{Period, Schedule, SchedulingError};
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn get_cal() -> BusinessCalendar {
+        BusinessCalendar::new(Some(vec![ymd("2029-05-11")]), "1111100")
+    }
+
+    // * semi-annual 5y bond, 2026-05-15 to 2031-05-15
+    fn coupons(cal: &BusinessCalendar) -> Schedule<'_> {
+        Schedule::builder()
+            .effective(ymd("2026-05-15"))
+            .termination(ymd("2031-05-15"))
+            .period(Period::Months(6))
+            .accrual_calendar(cal)
+            .accrual_convention(BusdayConvention::Following)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_european() {
+        let cal = get_cal();
+        let sch = OptionSchedule::builder(
+            OptionKind::Put,
+            Exercise::European(ymd("2029-05-15")),
+        )
+        .notice_days(10)
+        .notice_calendar(&cal)
+        .build(&coupons(&cal))
+        .unwrap();
+        assert_eq!(sch.kind(), OptionKind::Put);
+        assert_eq!(sch.windows().len(), 1);
+        let w = sch.windows()[0];
+        assert_eq!((w.start(), w.end()), (ymd("2029-05-15"), ymd("2029-05-15")));
+        // * 10 business days back, skipping the 2029-05-11 holiday
+        assert_eq!(w.notice(), ymd("2029-04-30"));
+        assert_eq!(w.strike(), 100.0);
+    }
+
+    #[test]
+    fn test_bermudan() {
+        // * callable after 3 years (non-call 3) on coupon dates at a declining premium
+        let cal = get_cal();
+        let strike = Strike::Schedule(vec![
+            (ymd("2029-05-15"), 102.0),
+            (ymd("2030-05-15"), 101.0),
+            (ymd("2030-11-15"), 100.0),
+        ]);
+        let exercise = Exercise::Bermudan {
+            first: ymd("2029-05-15"),
+        };
+        let sch = OptionSchedule::builder(OptionKind::Call, exercise)
+            .strike(strike)
+            .notice_days(5)
+            .notice_calendar(&cal)
+            .build(&coupons(&cal))
+            .unwrap();
+
+        let windows: Vec<_> = sch
+            .windows()
+            .iter()
+            .map(|w| (w.start(), w.notice(), w.strike()))
+            .collect();
+        assert_eq!(
+            windows,
+            [
+                (ymd("2029-05-15"), ymd("2029-05-07"), 102.0),
+                // * 2029-11-15 is a Thursday
+                (ymd("2029-11-15"), ymd("2029-11-08"), 102.0),
+                (ymd("2030-05-15"), ymd("2030-05-08"), 101.0),
+                // * 2030-11-15 is a Friday
+                (ymd("2030-11-15"), ymd("2030-11-08"), 100.0),
+            ]
+        );
+        assert!(sch.windows().iter().all(|w| w.start() == w.end()));
+
+        // * the first date matches the unadjusted coupon date 2027-05-15, a Saturday
+        // * paid on the preceding Friday
+        let coupons = Schedule::builder()
+            .effective(ymd("2026-05-15"))
+            .termination(ymd("2031-05-15"))
+            .period(Period::Months(6))
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::Preceding)
+            .build()
+            .unwrap();
+        let exercise = Exercise::Bermudan {
+            first: ymd("2027-05-15"),
+        };
+        let sch = OptionSchedule::builder(OptionKind::Call, exercise)
+            .build(&coupons)
+            .unwrap();
+        assert_eq!(sch.windows()[0].start(), ymd("2027-05-14"));
+        assert_eq!(sch.windows().len(), 8);
+    }
+
+    #[test]
+    fn test_american() {
+        let cal = get_cal();
+        let strike = Strike::Schedule(vec![
+            (ymd("2028-05-15"), 101.0),
+            (ymd("2029-05-15"), 100.0),
+        ]);
+        let exercise = Exercise::American {
+            start: ymd("2028-05-15"),
+            end: ymd("2031-05-15"),
+        };
+        let sch = OptionSchedule::builder(OptionKind::Call, exercise)
+            .strike(strike)
+            .build(&coupons(&cal))
+            .unwrap();
+
+        let windows: Vec<_> = sch
+            .windows()
+            .iter()
+            .map(|w| (w.start(), w.end(), w.strike()))
+            .collect();
+        assert_eq!(
+            windows,
+            [
+                (ymd("2028-05-15"), ymd("2029-05-14"), 101.0),
+                (ymd("2029-05-15"), ymd("2031-05-15"), 100.0),
+            ]
+        );
+        assert_eq!(sch.window_on(ymd("2029-01-02")).unwrap().strike(), 101.0);
+        assert!(sch.window_on(ymd("2027-01-04")).is_none());
+        assert_eq!(sch.windows()[0].notice(), ymd("2028-05-15"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let cal = get_cal();
+        let coupons = coupons(&cal);
+        let assert_invalid = |builder: OptionScheduleBuilder| {
+            let rslt = builder.build(&coupons);
+            assert!(
+                matches!(rslt, Err(SchedulingError::InvalidSchedule(_))),
+                "{rslt:?}"
+            );
+        };
+
+        let european = Exercise::European(ymd("2032-05-15"));
+        assert_invalid(OptionSchedule::builder(OptionKind::Call, european));
+
+        let american = Exercise::American {
+            start: ymd("2030-05-15"),
+            end: ymd("2029-05-15"),
+        };
+        assert_invalid(OptionSchedule::builder(OptionKind::Call, american));
+
+        // * only the maturity itself is on or after the first exercise date
+        let bermudan = Exercise::Bermudan {
+            first: ymd("2031-05-15"),
+        };
+        assert_invalid(OptionSchedule::builder(OptionKind::Call, bermudan));
+
+        let european = Exercise::European(ymd("2029-05-15"));
+        let builder = OptionSchedule::builder(OptionKind::Call, european);
+        assert_invalid(builder.clone().notice_days(5));
+        assert_invalid(builder.clone().strike(Strike::Schedule(vec![])));
+        assert_invalid(
+            builder.strike(Strike::Schedule(vec![(ymd("2030-05-15"), 100.0)])),
+        );
+    }
+}
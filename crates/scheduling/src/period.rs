@@ -1,3 +1,4 @@
+use std::fmt;
 use std::ops::{Add, Sub};
 
 use bizdate::{BusdayConvention, BusinessCalendar};
@@ -42,6 +43,70 @@ impl<'a> Period<'a> {
     pub fn is_days(&self) -> bool {
         matches!(self, Self::Days(_))
     }
+
+    /// Number of periods per year, if the period divides a year evenly enough to
+    /// define a coupon frequency (i.e. it is expressed in weeks, months or years).
+    pub fn frequency(&self) -> Option<f64> {
+        match *self {
+            Self::BusDays(..) | Self::Days(_) => None,
+            Self::Weeks(w) => Some(52.0 / w as f64),
+            Self::Months(m) => Some(12.0 / m as f64),
+            Self::Years(y) => Some(1.0 / y as f64),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for Period<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::BusDays(n, _) => write!(f, "BusDays({n})"),
+            Self::Days(n) => write!(f, "Days({n})"),
+            Self::Months(n) => write!(f, "Months({n})"),
+            Self::Weeks(n) => write!(f, "Weeks({n})"),
+            Self::Years(n) => write!(f, "Years({n})"),
+        }
+    }
+}
+
+// * calendars are compared by identity--two periods are only equal if they roll on
+// * the very same calendar
+impl<'a> PartialEq for Period<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::BusDays(n1, c1), Self::BusDays(n2, c2)) => {
+                n1 == n2 && std::ptr::eq(c1, c2)
+            }
+            (Self::Days(n1), Self::Days(n2)) => n1 == n2,
+            (Self::Months(n1), Self::Months(n2)) => n1 == n2,
+            (Self::Weeks(n1), Self::Weeks(n2)) => n1 == n2,
+            (Self::Years(n1), Self::Years(n2)) => n1 == n2,
+            _ => false,
+        }
+    }
+}
+
+/// A period applied before or after a date, e.g. a fixing two business days before
+/// the start of an accrual period.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Offset<'a> {
+    Before(Period<'a>),
+    After(Period<'a>),
+}
+
+impl<'a> Offset<'a> {
+    /// Get the date offset from ``dt``.
+    pub fn apply(&self, dt: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Self::Before(period) => period.prev(dt),
+            Self::After(period) => period.next(dt),
+        }
+    }
+
+    pub fn period(&self) -> Period<'a> {
+        match *self {
+            Self::Before(period) | Self::After(period) => period,
+        }
+    }
 }
 
 // TODO: how to handle potential errors here? Unwrap seems like the best bet...?
@@ -100,7 +165,9 @@ impl<'a> Sub<Period<'a>> for NaiveDate {
 mod tests {
     use chrono::NaiveDate;
 
-    use super::Period;
+    use bizdate::BusinessCalendar;
+
+    use super::{Offset, Period};
 
     #[test]
     fn test_add_months() {
@@ -146,4 +213,19 @@ mod tests {
         let rslt2 = NaiveDate::parse_from_str("2027-08-29", "%Y-%m-%d").unwrap();
         assert_eq!(dt2 - period, rslt2);
     }
+
+    #[test]
+    fn test_offset() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let dt = NaiveDate::parse_from_str("2026-03-02", "%Y-%m-%d").unwrap();
+
+        let before = Offset::Before(Period::BusDays(2, &cal));
+        let rslt = NaiveDate::parse_from_str("2026-02-26", "%Y-%m-%d").unwrap();
+        assert_eq!(before.apply(dt), Some(rslt));
+
+        let after = Offset::After(Period::Days(2));
+        let rslt = NaiveDate::parse_from_str("2026-03-04", "%Y-%m-%d").unwrap();
+        assert_eq!(after.apply(dt), Some(rslt));
+        assert_eq!(after.period(), Period::Days(2));
+    }
 }
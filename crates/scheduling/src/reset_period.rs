@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+
+/// A reset within an accrual period: the sub-period a single rate fixing applies to
+/// and the date that rate is fixed on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ResetPeriod {
+    pub(crate) start: NaiveDate,
+    pub(crate) end: NaiveDate,
+    pub(crate) start_adj: NaiveDate,
+    pub(crate) end_adj: NaiveDate,
+    pub(crate) fixing: NaiveDate,
+}
+
+impl ResetPeriod {
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        start_adj: NaiveDate,
+        end_adj: NaiveDate,
+        fixing: NaiveDate,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            start_adj,
+            end_adj,
+            fixing,
+        }
+    }
+
+    /// Get the unadjusted start date.
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Get the unadjusted end date.
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Get the business day-adjusted start date.
+    pub fn start_adj(&self) -> NaiveDate {
+        self.start_adj
+    }
+
+    /// Get the business day-adjusted end date.
+    pub fn end_adj(&self) -> NaiveDate {
+        self.end_adj
+    }
+
+    /// Get the date the rate for the reset is fixed on.
+    pub fn fixing(&self) -> NaiveDate {
+        self.fixing
+    }
+}
@@ -1,79 +1,266 @@
-use bizdate::{BusdayConvention, BusinessCalendar};
-use chrono::{Datelike, NaiveDate};
+use std::ops::Index;
+use std::slice;
+use std::vec;
 
-use crate::{Period, Roll, RollConvention, SchedulePeriod};
+use chrono::NaiveDate;
+
+use crate::adjuster::Adjuster;
+use crate::schedule_builder::invalid;
+use crate::{
+    AccrualDates, Amortization, Period, RollConvention, ScheduleBuilder,
+    SchedulePeriod, SchedulingError,
+};
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct Schedule {
-    periods: Vec<SchedulePeriod>,
-    // period: Period<'a>,
-    // roll_conv: RollConvention,
+pub struct Schedule<'a> {
+    pub(crate) periods: Vec<SchedulePeriod>,
+    pub(crate) period: Period<'a>,
+    pub(crate) roll_conv: RollConvention,
+    /// Whether an irregular first period is a front stub, false once sliced after the
+    /// original first period
+    pub(crate) front_stub: bool,
+    /// Accrual calendar and convention, to adjust the start of a truncated period
+    pub(crate) accrual: Option<Adjuster<'a>>,
 }
 
-impl Schedule {
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        effective: NaiveDate,
-        front_stub: Option<NaiveDate>,
-        back_stub: Option<NaiveDate>,
-        termination: NaiveDate,
-        pmt_cal: &BusinessCalendar,
-        busday_conv: BusdayConvention,
-        period: Period,
-        bom: bool,
-        eom: bool,
-    ) -> Self {
-        let mut periods = Vec::new();
-
-        // * handle front stub
-        if let Some(end) = front_stub {
-            periods.push(SchedulePeriod::new_adjust(
-                effective,
-                end,
-                pmt_cal,
-                busday_conv,
-            ));
-        }
+impl<'a> Schedule<'a> {
+    /// Get a [`ScheduleBuilder`] to generate a new schedule.
+    pub fn builder() -> ScheduleBuilder<'a> {
+        ScheduleBuilder::new()
+    }
 
-        let start = front_stub.unwrap_or(effective);
-        let end = back_stub.unwrap_or(termination);
-
-        let roll_conv = if period.is_days() {
-            RollConvention::None
-        } else if bom {
-            RollConvention::Bom
-        } else if eom {
-            RollConvention::Eom
-        } else {
-            RollConvention::DayOfMonth(start.day())
-        };
+    /// Get the regular period (coupon frequency) of the schedule.
+    pub fn period(&self) -> Period<'a> {
+        self.period
+    }
 
-        let mut tmp = start;
-        while tmp < end {
-            let start = tmp;
-            let end = roll_conv.next(start, period);
+    /// Get the roll convention used to generate the regular periods.
+    pub fn roll_conv(&self) -> RollConvention {
+        self.roll_conv
+    }
 
-            let period = SchedulePeriod::new_adjust(start, end, pmt_cal, busday_conv);
-            periods.push(period);
+    /// Get the number of periods in the schedule.
+    pub fn len(&self) -> usize {
+        self.periods.len()
+    }
 
-            tmp = end;
+    pub fn is_empty(&self) -> bool {
+        self.periods.is_empty()
+    }
+
+    /// Iterate over the periods in order.
+    pub fn iter(&self) -> slice::Iter<'_, SchedulePeriod> {
+        self.periods.iter()
+    }
+
+    /// Get the periods as a slice.
+    pub fn periods(&self) -> &[SchedulePeriod] {
+        &self.periods
+    }
+
+    pub fn first(&self) -> Option<&SchedulePeriod> {
+        self.periods.first()
+    }
+
+    pub fn last(&self) -> Option<&SchedulePeriod> {
+        self.periods.last()
+    }
+
+    /// Iterate over the unadjusted period start dates.
+    pub fn start_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().map(|p| p.start)
+    }
+
+    /// Iterate over the unadjusted period end dates.
+    pub fn end_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().map(|p| p.end)
+    }
+
+    /// Iterate over the adjusted period start dates.
+    pub fn adjusted_start_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().map(|p| p.start_adj)
+    }
+
+    /// Iterate over the adjusted period end dates.
+    pub fn adjusted_end_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().map(|p| p.end_adj)
+    }
+
+    /// Iterate over the payment dates.
+    pub fn payment_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().map(|p| p.pay)
+    }
+
+    /// Iterate over the notional outstanding in each period, empty until the schedule
+    /// is amortized.
+    pub fn notionals(&self) -> impl Iterator<Item = f64> + '_ {
+        self.iter().filter_map(|p| p.notional)
+    }
+
+    /// Attach an ``amortization`` profile of the original ``notional`` to the periods;
+    /// the principal repaid at the end of each period is the drop in notional, with
+    /// whatever is outstanding repaid at maturity.
+    pub fn amortize(
+        mut self,
+        notional: f64,
+        amortization: &Amortization,
+    ) -> Result<Self, SchedulingError> {
+        let notionals = amortization.notionals(notional, &self)?;
+        let next = notionals.iter().skip(1).chain([&0.0]);
+        for ((p, n), next) in self.periods.iter_mut().zip(&notionals).zip(next) {
+            p.notional = Some(*n);
+            p.principal = n - next;
         }
+        Ok(self)
+    }
 
-        // handle back stub--end of schedule thus far ends at back stub date
-        if let Some(start) = back_stub {
-            periods.push(SchedulePeriod::new_adjust(
-                start,
-                termination,
-                pmt_cal,
-                busday_conv,
-            ));
+    /// Iterate over the fixing dates of every reset in order.
+    pub fn fixing_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().flat_map(|p| p.resets.iter().map(|r| r.fixing))
+    }
+
+    /// Iterate over the record dates of the periods, if built with an ex-coupon
+    /// offset.
+    pub fn record_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().filter_map(|p| p.record)
+    }
+
+    /// Check if settlement on ``dt`` falls in the ex-coupon period of any coupon.
+    pub fn is_ex_coupon(&self, dt: NaiveDate) -> bool {
+        self.iter().any(|p| p.is_ex_coupon(dt))
+    }
+
+    /// Find the index and period containing ``dt``, i.e. the period with
+    /// $dt \in [start, end)$ measured on the unadjusted or adjusted ``dates``. A date
+    /// on a boundary belongs to the period starting there, and the termination date
+    /// to no period.
+    pub fn find_period(
+        &self,
+        dt: NaiveDate,
+        dates: AccrualDates,
+    ) -> Option<(usize, &SchedulePeriod)> {
+        let idx = self.partition(dt, dates).checked_sub(1)?;
+        let period = &self.periods[idx];
+        (dt < period.accrual_dates(dates).1).then_some((idx, period))
+    }
+
+    /// Get the last period boundary on or before ``dt``, or ``None`` before the start
+    /// of the schedule.
+    pub fn prev_boundary(
+        &self,
+        dt: NaiveDate,
+        dates: AccrualDates,
+    ) -> Option<NaiveDate> {
+        let idx = self.partition(dt, dates).checked_sub(1)?;
+        let (start, end) = self.periods[idx].accrual_dates(dates);
+        Some(if dt >= end { end } else { start })
+    }
+
+    /// Get the first period boundary strictly after ``dt``, or ``None`` on or after
+    /// the end of the schedule.
+    pub fn next_boundary(
+        &self,
+        dt: NaiveDate,
+        dates: AccrualDates,
+    ) -> Option<NaiveDate> {
+        let idx = self.partition(dt, dates);
+        match self.periods.get(idx) {
+            Some(p) => Some(p.accrual_dates(dates).0),
+            None => self
+                .last()
+                .map(|p| p.accrual_dates(dates).1)
+                .filter(|end| dt < *end),
         }
+    }
 
-        Self {
-            periods,
-            // period,
-            // roll_conv,
+    /// Get the periods starting on or after ``dt``, e.g. those left to run on a
+    /// valuation date.
+    pub fn after(&self, dt: NaiveDate) -> Result<Self, SchedulingError> {
+        let idx = self.periods.partition_point(|p| p.start < dt);
+        self.slice(idx, self.len())
+            .ok_or_else(|| invalid(format!("no periods start on or after {dt}")))
+    }
+
+    /// Get the periods falling entirely within $[from, to]$.
+    pub fn between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Self, SchedulingError> {
+        let first = self.periods.partition_point(|p| p.start < from);
+        let last = self.periods.partition_point(|p| p.end <= to);
+        self.slice(first, last)
+            .ok_or_else(|| invalid(format!("no periods between {from} and {to}")))
+    }
+
+    /// Get the schedule forward-starting on ``dt``, e.g. the remainder of a trade
+    /// novated or partially terminated on ``dt``. The period containing ``dt`` is
+    /// truncated to start there and keeps its original dates as its reference period.
+    pub fn truncate(&self, dt: NaiveDate) -> Result<Self, SchedulingError> {
+        let Some((idx, period)) = self.find_period(dt, AccrualDates::Unadjusted) else {
+            return self.after(dt).map_err(|_| {
+                invalid(format!("{dt} is after the end of the schedule"))
+            });
+        };
+        let mut sch = self.slice(idx, self.len()).unwrap();
+        if dt > period.start {
+            let first = &mut sch.periods[0];
+            first.reference = Some(period.reference_period());
+            let dt_adj = self.accrual.map_or(dt, |a| a.adjust(dt));
+            first.start = dt;
+            first.start_adj = dt_adj;
+            // * resets that ended before ``dt`` have fixed and are dropped
+            first.resets.retain(|r| r.end > dt);
+            if let Some(reset) = first.resets.first_mut() {
+                reset.start = reset.start.max(dt);
+                reset.start_adj = reset.start_adj.max(dt_adj);
+            }
         }
+        Ok(sch)
+    }
+
+    /// Copy the periods in ``[first, last)``, or ``None`` if there are none.
+    fn slice(&self, first: usize, last: usize) -> Option<Self> {
+        (first < last).then(|| Self {
+            periods: self.periods[first..last].to_vec(),
+            period: self.period,
+            roll_conv: self.roll_conv,
+            front_stub: self.front_stub && first == 0,
+            accrual: self.accrual,
+        })
+    }
+
+    /// Get the number of periods starting on or before ``dt``; the periods are
+    /// contiguous, so their start dates are sorted.
+    fn partition(&self, dt: NaiveDate, dates: AccrualDates) -> usize {
+        self.periods
+            .partition_point(|p| p.accrual_dates(dates).0 <= dt)
+    }
+}
+
+impl<'a> Index<usize> for Schedule<'a> {
+    type Output = SchedulePeriod;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.periods[index]
+    }
+}
+
+impl<'s, 'a> IntoIterator for &'s Schedule<'a> {
+    type Item = &'s SchedulePeriod;
+    type IntoIter = slice::Iter<'s, SchedulePeriod>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.periods.iter()
+    }
+}
+
+impl<'a> IntoIterator for Schedule<'a> {
+    type Item = SchedulePeriod;
+    type IntoIter = vec::IntoIter<SchedulePeriod>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.periods.into_iter()
     }
 }
 
@@ -82,7 +269,7 @@ mod tests {
     use bizdate::{BusdayConvention, BusinessCalendar};
     use chrono::NaiveDate;
 
-    use crate::{Period, SchedulePeriod};
+    use crate::{AccrualDates, Period, SchedulePeriod};
 
     use super::Schedule;
 
@@ -93,17 +280,14 @@ mod tests {
         let termination = NaiveDate::from_ymd_opt(2028, 2, 15).unwrap();
         let period = Period::Months(6);
         let busday_conv = BusdayConvention::Following;
-        let sch = Schedule::new(
-            effective,
-            None,
-            None,
-            termination,
-            &cal,
-            busday_conv,
-            period,
-            false,
-            false,
-        );
+        let sch = Schedule::builder()
+            .effective(effective)
+            .termination(termination)
+            .accrual_calendar(&cal)
+            .accrual_convention(busday_conv)
+            .period(period)
+            .build()
+            .unwrap();
         let periods = vec![
             SchedulePeriod::new_adjust(
                 NaiveDate::from_ymd_opt(2026, 2, 15).unwrap(),
@@ -130,7 +314,7 @@ mod tests {
                 busday_conv,
             ),
         ];
-        assert_eq!(Schedule { periods }, sch)
+        assert_eq!(periods, sch.periods)
     }
 
     #[test]
@@ -141,17 +325,15 @@ mod tests {
         let termination = NaiveDate::from_ymd_opt(2028, 2, 15).unwrap();
         let period = Period::Months(6);
         let busday_conv = BusdayConvention::Following;
-        let sch = Schedule::new(
-            effective,
-            Some(front_stub),
-            None,
-            termination,
-            &cal,
-            busday_conv,
-            period,
-            false,
-            false,
-        );
+        let sch = Schedule::builder()
+            .effective(effective)
+            .first_regular(front_stub)
+            .termination(termination)
+            .accrual_calendar(&cal)
+            .accrual_convention(busday_conv)
+            .period(period)
+            .build()
+            .unwrap();
         let periods = vec![
             SchedulePeriod::new_adjust(
                 NaiveDate::from_ymd_opt(2025, 12, 15).unwrap(),
@@ -178,7 +360,7 @@ mod tests {
                 busday_conv,
             ),
         ];
-        assert_eq!(Schedule { periods }, sch)
+        assert_eq!(periods, sch.periods)
     }
 
     #[test]
@@ -189,17 +371,15 @@ mod tests {
         let termination = NaiveDate::from_ymd_opt(2028, 2, 15).unwrap();
         let period = Period::Months(6);
         let busday_conv = BusdayConvention::Following;
-        let sch = Schedule::new(
-            effective,
-            Some(front_stub),
-            None,
-            termination,
-            &cal,
-            busday_conv,
-            period,
-            false,
-            false,
-        );
+        let sch = Schedule::builder()
+            .effective(effective)
+            .first_regular(front_stub)
+            .termination(termination)
+            .accrual_calendar(&cal)
+            .accrual_convention(busday_conv)
+            .period(period)
+            .build()
+            .unwrap();
         let periods = vec![
             SchedulePeriod::new_adjust(
                 NaiveDate::from_ymd_opt(2026, 4, 30).unwrap(),
@@ -226,7 +406,7 @@ mod tests {
                 busday_conv,
             ),
         ];
-        assert_eq!(Schedule { periods }, sch)
+        assert_eq!(periods, sch.periods)
     }
 
     #[test]
@@ -237,17 +417,15 @@ mod tests {
         let termination = NaiveDate::from_ymd_opt(2028, 6, 15).unwrap();
         let period = Period::Months(6);
         let busday_conv = BusdayConvention::Following;
-        let sch = Schedule::new(
-            effective,
-            None,
-            Some(back_stub),
-            termination,
-            &cal,
-            busday_conv,
-            period,
-            false,
-            false,
-        );
+        let sch = Schedule::builder()
+            .effective(effective)
+            .last_regular(back_stub)
+            .termination(termination)
+            .accrual_calendar(&cal)
+            .accrual_convention(busday_conv)
+            .period(period)
+            .build()
+            .unwrap();
         let periods = vec![
             SchedulePeriod::new_adjust(
                 NaiveDate::from_ymd_opt(2026, 2, 15).unwrap(),
@@ -274,7 +452,7 @@ mod tests {
                 busday_conv,
             ),
         ];
-        assert_eq!(Schedule { periods }, sch)
+        assert_eq!(periods, sch.periods)
     }
 
     #[test]
@@ -285,17 +463,15 @@ mod tests {
         let termination = NaiveDate::from_ymd_opt(2028, 6, 15).unwrap();
         let period = Period::Months(6);
         let busday_conv = BusdayConvention::Following;
-        let sch = Schedule::new(
-            effective,
-            None,
-            Some(back_stub),
-            termination,
-            &cal,
-            busday_conv,
-            period,
-            false,
-            false,
-        );
+        let sch = Schedule::builder()
+            .effective(effective)
+            .last_regular(back_stub)
+            .termination(termination)
+            .accrual_calendar(&cal)
+            .accrual_convention(busday_conv)
+            .period(period)
+            .build()
+            .unwrap();
         let periods = vec![
             SchedulePeriod::new_adjust(
                 NaiveDate::from_ymd_opt(2026, 2, 15).unwrap(),
@@ -328,6 +504,188 @@ mod tests {
                 busday_conv,
             ),
         ];
-        assert_eq!(Schedule { periods }, sch)
+        assert_eq!(periods, sch.periods)
+    }
+
+    #[test]
+    fn test_accessors() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = Schedule::builder()
+            .effective(NaiveDate::from_ymd_opt(2026, 2, 15).unwrap())
+            .termination(NaiveDate::from_ymd_opt(2027, 2, 15).unwrap())
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::Following)
+            .period(Period::Months(6))
+            .build()
+            .unwrap();
+
+        assert_eq!(sch.len(), 2);
+        assert!(!sch.is_empty());
+        assert_eq!(sch.first(), Some(&sch[0]));
+        assert_eq!(sch.last(), Some(&sch[1]));
+        assert_eq!(sch.iter().count(), 2);
+        assert_eq!(
+            (&sch).into_iter().collect::<Vec<_>>(),
+            sch.periods().iter().collect::<Vec<_>>()
+        );
+
+        let dates = |ds: &[(i32, u32, u32)]| -> Vec<NaiveDate> {
+            ds.iter()
+                .map(|&(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap())
+                .collect()
+        };
+        assert_eq!(
+            sch.start_dates().collect::<Vec<_>>(),
+            dates(&[(2026, 2, 15), (2026, 8, 15)])
+        );
+        assert_eq!(
+            sch.end_dates().collect::<Vec<_>>(),
+            dates(&[(2026, 8, 15), (2027, 2, 15)])
+        );
+        assert_eq!(
+            sch.adjusted_start_dates().collect::<Vec<_>>(),
+            dates(&[(2026, 2, 16), (2026, 8, 17)])
+        );
+        assert_eq!(
+            sch.adjusted_end_dates().collect::<Vec<_>>(),
+            dates(&[(2026, 8, 17), (2027, 2, 15)])
+        );
+        assert_eq!(sch.record_dates().count(), 0);
+        assert!(!sch.is_ex_coupon(NaiveDate::from_ymd_opt(2026, 8, 14).unwrap()));
+
+        let periods = sch.periods().to_vec();
+        assert_eq!(sch.into_iter().collect::<Vec<_>>(), periods);
+    }
+
+    #[test]
+    fn test_find_period() {
+        // * 2026-02-15 to 2027-02-15 semi-annual; 2026-02-15 and 2026-08-15 are a
+        // * Sunday and a Saturday, adjusted to 2026-02-16 and 2026-08-17
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = Schedule::builder()
+            .effective(NaiveDate::from_ymd_opt(2026, 2, 15).unwrap())
+            .termination(NaiveDate::from_ymd_opt(2027, 2, 15).unwrap())
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::Following)
+            .period(Period::Months(6))
+            .build()
+            .unwrap();
+
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let lookup = |dt, dates| {
+            (
+                sch.find_period(dt, dates).map(|(i, _)| i),
+                sch.prev_boundary(dt, dates),
+                sch.next_boundary(dt, dates),
+            )
+        };
+        let unadj = AccrualDates::Unadjusted;
+        let adj = AccrualDates::Adjusted;
+
+        // * before the schedule
+        assert_eq!(
+            lookup(ymd(2026, 2, 14), unadj),
+            (None, None, Some(ymd(2026, 2, 15)))
+        );
+        assert_eq!(
+            lookup(ymd(2026, 2, 15), adj),
+            (None, None, Some(ymd(2026, 2, 16)))
+        );
+
+        // * a boundary belongs to the period starting on it
+        let (idx, period) = sch.find_period(ymd(2026, 8, 15), unadj).unwrap();
+        assert_eq!((idx, period), (1, &sch[1]));
+        assert_eq!(
+            lookup(ymd(2026, 2, 15), unadj),
+            (Some(0), Some(ymd(2026, 2, 15)), Some(ymd(2026, 8, 15)))
+        );
+        assert_eq!(
+            lookup(ymd(2026, 8, 15), unadj),
+            (Some(1), Some(ymd(2026, 8, 15)), Some(ymd(2027, 2, 15)))
+        );
+
+        // * between the unadjusted and adjusted boundary
+        assert_eq!(
+            lookup(ymd(2026, 8, 16), adj),
+            (Some(0), Some(ymd(2026, 2, 16)), Some(ymd(2026, 8, 17)))
+        );
+
+        // * on or after termination
+        for dt in [ymd(2027, 2, 15), ymd(2027, 3, 1)] {
+            assert_eq!(lookup(dt, unadj), (None, Some(ymd(2027, 2, 15)), None));
+        }
+    }
+
+    #[test]
+    fn test_slicing() {
+        // * semi-annual 2026-02-15 to 2028-02-15
+        let sch = Schedule::builder()
+            .effective(NaiveDate::from_ymd_opt(2026, 2, 15).unwrap())
+            .termination(NaiveDate::from_ymd_opt(2028, 2, 15).unwrap())
+            .period(Period::Months(6))
+            .reset_period(Period::Months(3))
+            .build()
+            .unwrap();
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let after = sch.after(ymd(2026, 8, 15)).unwrap();
+        assert_eq!(after.periods(), &sch.periods()[1..]);
+        assert!(!after.front_stub);
+        assert_eq!(
+            sch.after(ymd(2026, 8, 16)).unwrap().periods(),
+            &sch.periods()[2..]
+        );
+        assert_eq!(sch.after(ymd(2026, 1, 1)).unwrap(), sch);
+        assert!(sch.after(ymd(2027, 8, 16)).is_err());
+
+        let between = sch.between(ymd(2026, 8, 1), ymd(2027, 8, 15)).unwrap();
+        assert_eq!(between.periods(), &sch.periods()[1..3]);
+        assert!(sch.between(ymd(2026, 8, 16), ymd(2027, 8, 14)).is_err());
+
+        // * truncating keeps the original period as the reference and drops the reset
+        // * that has already ended
+        let truncated = sch.truncate(ymd(2026, 12, 1)).unwrap();
+        assert_eq!(truncated.len(), 3);
+        assert_eq!(truncated.periods()[1..], sch.periods()[2..]);
+        let first = &truncated[0];
+        assert_eq!(
+            (first.start(), first.start_adj()),
+            (ymd(2026, 12, 1), ymd(2026, 12, 1))
+        );
+        assert_eq!(first.end(), ymd(2027, 2, 15));
+        assert_eq!(
+            first.reference_period(),
+            (ymd(2026, 8, 15), ymd(2027, 2, 15))
+        );
+        assert_eq!(first.resets().len(), 1);
+        assert_eq!(first.resets()[0].start(), ymd(2026, 12, 1));
+        assert_eq!(first.fixing(), Some(sch[1].resets()[1].fixing()));
+
+        // * truncating again keeps the original reference period
+        let again = truncated.truncate(ymd(2027, 1, 1)).unwrap();
+        assert_eq!(
+            again[0].reference_period(),
+            (ymd(2026, 8, 15), ymd(2027, 2, 15))
+        );
+
+        assert_eq!(sch.truncate(ymd(2026, 8, 15)).unwrap(), after);
+        assert!(sch.truncate(ymd(2028, 2, 15)).is_err());
+
+        // * the truncated start is adjusted like any other accrual date; 2026-11-28
+        // * is a Saturday
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = Schedule::builder()
+            .effective(ymd(2026, 2, 15))
+            .termination(ymd(2028, 2, 15))
+            .period(Period::Months(6))
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::Following)
+            .build()
+            .unwrap();
+        let first = &sch.truncate(ymd(2026, 11, 28)).unwrap()[0];
+        assert_eq!(
+            (first.start(), first.start_adj()),
+            (ymd(2026, 11, 28), ymd(2026, 11, 30))
+        );
     }
 }
@@ -0,0 +1,1310 @@
+use bizdate::{BusdayConvention, BusinessCalendar};
+use chrono::{Datelike, NaiveDate};
+
+use crate::adjuster::Adjuster;
+use crate::period::Offset;
+use crate::{
+    Period, ResetPeriod, Roll, RollConvention, Schedule, SchedulePeriod,
+    SchedulingError,
+};
+
+/// Direction the regular periods are rolled in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GenerationDirection {
+    /// Roll forward from the effective date (or first regular date); any remainder
+    /// ends up at the back of the schedule
+    #[default]
+    Forward,
+    /// Roll backward from the termination date (or last regular date); any remainder
+    /// ends up at the front of the schedule as a short stub
+    Backward,
+}
+
+/// Period boundary a payment or fixing date is measured from.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RelativeTo {
+    /// Start of the period, i.e. in advance
+    PeriodStart,
+    /// End of the period, i.e. in arrears
+    #[default]
+    PeriodEnd,
+}
+
+/// Period boundary a payment date is measured from; kept for compatibility, see
+/// [`RelativeTo`].
+pub type PaymentRelativeTo = RelativeTo;
+
+impl RelativeTo {
+    /// Pick the boundary of the period between ``start`` and ``end``.
+    fn pick(&self, start: NaiveDate, end: NaiveDate) -> NaiveDate {
+        match self {
+            Self::PeriodStart => start,
+            Self::PeriodEnd => end,
+        }
+    }
+}
+
+/// Where and how stubs are inferred when their dates are not given explicitly.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StubConvention {
+    /// No stub; the regular periods must divide the schedule evenly
+    None,
+    /// Short stub at the front, periods rolled backward
+    ShortInitial,
+    /// Long stub at the front, periods rolled backward
+    LongInitial,
+    /// Short stub at the back, periods rolled forward
+    ShortFinal,
+    /// Long stub at the back, periods rolled forward
+    LongFinal,
+    /// Short initial stub unless it would be shorter than a week, then long
+    SmartInitial,
+    /// Short final stub unless it would be shorter than a week, then long
+    SmartFinal,
+}
+
+impl StubConvention {
+    /// Smart stubs shorter than this number of days are merged into a long stub.
+    const SMART_STUB_DAYS: i64 = 7;
+
+    pub fn is_initial(&self) -> bool {
+        matches!(
+            self,
+            Self::ShortInitial | Self::LongInitial | Self::SmartInitial
+        )
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::ShortFinal | Self::LongFinal | Self::SmartFinal)
+    }
+
+    /// Get the generation direction implied by the convention, if any.
+    pub fn direction(&self) -> Option<GenerationDirection> {
+        if self.is_initial() {
+            Some(GenerationDirection::Backward)
+        } else if self.is_final() {
+            Some(GenerationDirection::Forward)
+        } else {
+            None
+        }
+    }
+
+    /// Pick the stub boundary given the short and long candidates and the length of
+    /// the short stub in days.
+    fn choose(&self, short: NaiveDate, long: NaiveDate, short_days: i64) -> NaiveDate {
+        match self {
+            Self::LongInitial | Self::LongFinal => long,
+            Self::SmartInitial | Self::SmartFinal
+                if short_days < Self::SMART_STUB_DAYS =>
+            {
+                long
+            }
+            _ => short,
+        }
+    }
+}
+
+/// Builder for a [`Schedule`]; the combination of inputs is validated by
+/// [`ScheduleBuilder::build`].
+///
+/// Effective date, termination date and period are required. Without an accrual
+/// calendar the adjusted dates equal the unadjusted ones; without a payment lag or
+/// calendar payments fall on the adjusted period end.
+#[derive(Clone, Copy, Default)]
+pub struct ScheduleBuilder<'a> {
+    effective: Option<NaiveDate>,
+    termination: Option<NaiveDate>,
+    first_regular: Option<NaiveDate>,
+    last_regular: Option<NaiveDate>,
+    period: Option<Period<'a>>,
+    roll_conv: Option<RollConvention>,
+    direction: Option<GenerationDirection>,
+    stub: Option<StubConvention>,
+    accrual_cal: Option<&'a BusinessCalendar>,
+    accrual_conv: Option<BusdayConvention>,
+    payment_cal: Option<&'a BusinessCalendar>,
+    payment_conv: Option<BusdayConvention>,
+    payment_lag: Option<Period<'a>>,
+    payment_relative_to: Option<RelativeTo>,
+    reset_period: Option<Period<'a>>,
+    fixing_offset: Option<Offset<'a>>,
+    fixing_relative_to: Option<RelativeTo>,
+    fixing_cal: Option<&'a BusinessCalendar>,
+    fixing_conv: Option<BusdayConvention>,
+    ex_coupon: Option<Period<'a>>,
+    ex_coupon_cal: Option<&'a BusinessCalendar>,
+    ex_coupon_conv: Option<BusdayConvention>,
+}
+
+impl<'a> ScheduleBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the start date of the first period.
+    pub fn effective(mut self, dt: NaiveDate) -> Self {
+        self.effective = Some(dt);
+        self
+    }
+
+    /// Set the end date of the last period.
+    pub fn termination(mut self, dt: NaiveDate) -> Self {
+        self.termination = Some(dt);
+        self
+    }
+
+    /// Set the start of the first regular period; the period before it is a front
+    /// stub.
+    pub fn first_regular(mut self, dt: NaiveDate) -> Self {
+        self.first_regular = Some(dt);
+        self
+    }
+
+    /// Set the end of the last regular period; the period after it is a back stub.
+    pub fn last_regular(mut self, dt: NaiveDate) -> Self {
+        self.last_regular = Some(dt);
+        self
+    }
+
+    /// Set the length of a regular period, i.e. the frequency of the schedule.
+    pub fn period(mut self, period: Period<'a>) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// Set the roll convention of the regular periods. Defaults to rolling on the
+    /// day of month of the date generation starts from (see
+    /// [`ScheduleBuilder::direction`]) for month and year periods, and to no roll
+    /// adjustment otherwise.
+    pub fn roll_conv(mut self, roll_conv: RollConvention) -> Self {
+        self.roll_conv = Some(roll_conv);
+        self
+    }
+
+    /// Set the direction the regular periods are generated in. Defaults to the
+    /// direction implied by the stub convention, or else
+    /// [`GenerationDirection::Forward`].
+    pub fn direction(mut self, direction: GenerationDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Set the stub convention used to infer the first or last regular date when not
+    /// given explicitly. Once set, whatever is left between the stubs must be a whole
+    /// number of regular periods.
+    pub fn stub(mut self, stub: StubConvention) -> Self {
+        self.stub = Some(stub);
+        self
+    }
+
+    /// Set the business calendar the accrual dates are adjusted on.
+    pub fn accrual_calendar(mut self, cal: &'a BusinessCalendar) -> Self {
+        self.accrual_cal = Some(cal);
+        self
+    }
+
+    /// Set the business day convention the accrual dates are adjusted with.
+    pub fn accrual_convention(mut self, conv: BusdayConvention) -> Self {
+        self.accrual_conv = Some(conv);
+        self
+    }
+
+    /// Set the business calendar the payment dates are adjusted on.
+    pub fn payment_calendar(mut self, cal: &'a BusinessCalendar) -> Self {
+        self.payment_cal = Some(cal);
+        self
+    }
+
+    /// Set the business day convention the payment dates are adjusted with.
+    pub fn payment_convention(mut self, conv: BusdayConvention) -> Self {
+        self.payment_conv = Some(conv);
+        self
+    }
+
+    /// Set the offset of the payment date from the adjusted period boundary, in
+    /// business days ([`Period::BusDays`]) or calendar days ([`Period::Days`]). The
+    /// lagged date is then adjusted on the payment calendar, if any.
+    pub fn payment_lag(mut self, lag: Period<'a>) -> Self {
+        self.payment_lag = Some(lag);
+        self
+    }
+
+    /// Set whether payments are made relative to the start (in advance) or end (in
+    /// arrears) of each period. Defaults to [`RelativeTo::PeriodEnd`].
+    pub fn payment_relative_to(mut self, relative_to: RelativeTo) -> Self {
+        self.payment_relative_to = Some(relative_to);
+        self
+    }
+
+    /// Set the length of the resets within each accrual period, e.g. weekly resets
+    /// on a quarterly leg. Resets are rolled forward from the start of each period and
+    /// default to one per period once any fixing input is set.
+    pub fn reset_period(mut self, period: Period<'a>) -> Self {
+        self.reset_period = Some(period);
+        self
+    }
+
+    /// Set the offset of the fixing date from the adjusted reset boundary, in
+    /// business or calendar days, e.g. ``Offset::Before(Period::BusDays(2, &cal))``.
+    pub fn fixing_offset(mut self, offset: Offset<'a>) -> Self {
+        self.fixing_offset = Some(offset);
+        self
+    }
+
+    /// Set whether fixings are taken relative to the start (in advance) or end (in
+    /// arrears) of each reset. Defaults to [`RelativeTo::PeriodStart`].
+    pub fn fixing_relative_to(mut self, relative_to: RelativeTo) -> Self {
+        self.fixing_relative_to = Some(relative_to);
+        self
+    }
+
+    /// Set the business calendar the fixing dates are adjusted on. With a fixing
+    /// offset in business days this must be the calendar the offset counts on.
+    pub fn fixing_calendar(mut self, cal: &'a BusinessCalendar) -> Self {
+        self.fixing_cal = Some(cal);
+        self
+    }
+
+    /// Set the business day convention the fixing dates are adjusted with.
+    pub fn fixing_convention(mut self, conv: BusdayConvention) -> Self {
+        self.fixing_conv = Some(conv);
+        self
+    }
+
+    /// Set how long before the payment date each coupon goes ex, in business or
+    /// calendar days, e.g. ``Period::BusDays(7, &cal)`` for gilts.
+    pub fn ex_coupon(mut self, offset: Period<'a>) -> Self {
+        self.ex_coupon = Some(offset);
+        self
+    }
+
+    /// Set the business calendar the record dates are adjusted on.
+    pub fn ex_coupon_calendar(mut self, cal: &'a BusinessCalendar) -> Self {
+        self.ex_coupon_cal = Some(cal);
+        self
+    }
+
+    /// Set the business day convention the record dates are adjusted with.
+    pub fn ex_coupon_convention(mut self, conv: BusdayConvention) -> Self {
+        self.ex_coupon_conv = Some(conv);
+        self
+    }
+
+    /// Validate the inputs and generate the schedule.
+    pub fn build(&self) -> Result<Schedule<'a>, SchedulingError> {
+        let effective = self
+            .effective
+            .ok_or_else(|| invalid("missing effective date"))?;
+        let termination = self
+            .termination
+            .ok_or_else(|| invalid("missing termination date"))?;
+        let period = self.period.ok_or_else(|| invalid("missing period"))?;
+
+        if effective >= termination {
+            return Err(invalid(format!(
+                "effective date {effective} must be before termination date \
+                 {termination}"
+            )));
+        }
+        if period.next(effective).is_none_or(|dt| dt <= effective) {
+            return Err(invalid(format!("period {period:?} must be positive")));
+        }
+        if let Some(dt) = self.first_regular
+            && (dt <= effective || dt > termination)
+        {
+            return Err(invalid(format!(
+                "first regular date {dt} must be in ({effective}, {termination}]"
+            )));
+        }
+        if let Some(dt) = self.last_regular
+            && (dt < effective || dt >= termination)
+        {
+            return Err(invalid(format!(
+                "last regular date {dt} must be in [{effective}, {termination})"
+            )));
+        }
+        if let (Some(first), Some(last)) = (self.first_regular, self.last_regular)
+            && first >= last
+        {
+            return Err(invalid(format!(
+                "first regular date {first} must be before last regular date {last}"
+            )));
+        }
+
+        if let Some(lag) = self.payment_lag
+            && !is_day_count(lag)
+        {
+            return Err(invalid(format!(
+                "payment lag {lag:?} must be in business or calendar days"
+            )));
+        }
+        if let Some(offset) = self.fixing_offset
+            && !is_day_count(offset.period())
+        {
+            return Err(invalid(format!(
+                "fixing offset {offset:?} must be in business or calendar days"
+            )));
+        }
+        if let Some(Period::BusDays(_, offset_cal)) =
+            self.fixing_offset.map(|o| o.period())
+            && let Some(cal) = self.fixing_cal
+            && !std::ptr::eq(offset_cal, cal)
+        {
+            return Err(invalid(
+                "fixing offset counts business days on a calendar other than the \
+                 fixing calendar",
+            ));
+        }
+        if let Some(offset) = self.ex_coupon
+            && !is_day_count(offset)
+        {
+            return Err(invalid(format!(
+                "ex-coupon offset {offset:?} must be in business or calendar days"
+            )));
+        }
+        if let Some(reset) = self.reset_period
+            && reset.next(effective).is_none_or(|dt| dt <= effective)
+        {
+            return Err(invalid(format!("reset period {reset:?} must be positive")));
+        }
+
+        let direction = self.resolve_direction()?;
+        let anchor = match direction {
+            GenerationDirection::Forward => self.first_regular.unwrap_or(effective),
+            GenerationDirection::Backward => self.last_regular.unwrap_or(termination),
+        };
+        let rolls_on_month = matches!(period, Period::Months(_) | Period::Years(_));
+        let roll_conv = match self.roll_conv {
+            Some(RollConvention::None) | None if !rolls_on_month => {
+                RollConvention::None
+            }
+            Some(roll_conv) if rolls_on_month => roll_conv,
+            None => RollConvention::DayOfMonth(anchor.day()),
+            Some(roll_conv) => {
+                return Err(invalid(format!(
+                    "roll convention {roll_conv:?} requires a month or year period, \
+                     got {period:?}"
+                )));
+            }
+        };
+
+        let (first_regular, last_regular) =
+            self.infer_stubs(effective, termination, roll_conv, period);
+        let start = first_regular.unwrap_or(effective);
+        let end = last_regular.unwrap_or(termination);
+
+        // * without a stub convention the remainder becomes a short stub at the far
+        // * end of the generation, unless that end is an explicit regular date
+        let (dates, exact) = regular_dates(start, end, roll_conv, period, direction);
+        let explicit_far_end = match direction {
+            GenerationDirection::Forward => last_regular.is_some(),
+            GenerationDirection::Backward => first_regular.is_some(),
+        };
+        if !exact && (self.stub.is_some() || explicit_far_end) {
+            return Err(invalid(format!(
+                "{start} to {end} is not a whole number of {period:?} periods rolled \
+                 with {roll_conv:?}"
+            )));
+        }
+
+        let accrual = adjuster("accrual", self.accrual_cal, self.accrual_conv)?;
+        let payment = adjuster("payment", self.payment_cal, self.payment_conv)?;
+        let fixing = adjuster("fixing", self.fixing_cal, self.fixing_conv)?;
+        let ex_coupon = adjuster("ex-coupon", self.ex_coupon_cal, self.ex_coupon_conv)?;
+        if ex_coupon.is_some() && self.ex_coupon.is_none() {
+            return Err(invalid(
+                "ex-coupon calendar given without an ex-coupon offset",
+            ));
+        }
+        let has_resets = self.reset_period.is_some()
+            || self.fixing_offset.is_some()
+            || self.fixing_relative_to.is_some()
+            || fixing.is_some();
+        let pay_relative_to = self.payment_relative_to.unwrap_or_default();
+        let fix_relative_to =
+            self.fixing_relative_to.unwrap_or(RelativeTo::PeriodStart);
+        // * resets on a month period roll like the schedule itself
+        let reset_roll = match self.reset_period {
+            Some(Period::Months(_) | Period::Years(_)) => roll_conv,
+            _ => RollConvention::None,
+        };
+
+        let adjust = |dt| accrual.as_ref().map_or(dt, |a| a.adjust(dt));
+        let new_reset = |start: NaiveDate, end: NaiveDate| {
+            let (start_adj, end_adj) = (adjust(start), adjust(end));
+            let base = fix_relative_to.pick(start_adj, end_adj);
+            // * offsets are validated as day counts, which always roll
+            let fixed = self.fixing_offset.map_or(base, |o| o.apply(base).unwrap());
+            let fixed = fixing.as_ref().map_or(fixed, |f| f.adjust(fixed));
+            ResetPeriod::new(start, end, start_adj, end_adj, fixed)
+        };
+        let new_period = |start: NaiveDate, end: NaiveDate| {
+            let mut sp = SchedulePeriod::new(start, end, adjust(start), adjust(end));
+            let base = pay_relative_to.pick(sp.start_adj, sp.end_adj);
+            let lagged = self.payment_lag.map_or(base, |lag| lag.next(base).unwrap());
+            sp.pay = payment.as_ref().map_or(lagged, |p| p.adjust(lagged));
+            sp.record = self.ex_coupon.map(|offset| {
+                let record = offset.prev(sp.pay).unwrap();
+                ex_coupon.as_ref().map_or(record, |e| e.adjust(record))
+            });
+            if has_resets {
+                sp.resets = match self.reset_period {
+                    Some(reset) => {
+                        let dates = regular_dates(
+                            start,
+                            end,
+                            reset_roll,
+                            reset,
+                            GenerationDirection::Forward,
+                        )
+                        .0;
+                        dates.windows(2).map(|w| new_reset(w[0], w[1])).collect()
+                    }
+                    None => vec![new_reset(start, end)],
+                };
+            }
+            sp
+        };
+
+        let mut periods = Vec::new();
+
+        // * handle front stub
+        if first_regular.is_some() {
+            periods.push(new_period(effective, start));
+        }
+
+        periods.extend(dates.windows(2).map(|w| new_period(w[0], w[1])));
+
+        // * handle back stub--end of schedule thus far ends at last regular date
+        if last_regular.is_some() {
+            periods.push(new_period(end, termination));
+        }
+
+        Ok(Schedule {
+            periods,
+            period,
+            roll_conv,
+            front_stub: true,
+            accrual,
+        })
+    }
+}
+
+impl<'a> ScheduleBuilder<'a> {
+    /// Resolve the generation direction, which is implied by initial and final stub
+    /// conventions.
+    fn resolve_direction(&self) -> Result<GenerationDirection, SchedulingError> {
+        let implied = self.stub.and_then(|stub| stub.direction());
+        match (implied, self.direction) {
+            (Some(implied), Some(direction)) if implied != direction => {
+                Err(invalid(format!(
+                    "stub convention {:?} cannot be generated {direction:?}",
+                    self.stub.unwrap()
+                )))
+            }
+            (implied, direction) => Ok(implied.or(direction).unwrap_or_default()),
+        }
+    }
+
+    /// Work out the first and last regular dates implied by the stub convention
+    /// where they were not given explicitly.
+    fn infer_stubs(
+        &self,
+        effective: NaiveDate,
+        termination: NaiveDate,
+        roll_conv: RollConvention,
+        period: Period,
+    ) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        let (mut first, mut last) = (self.first_regular, self.last_regular);
+        let start = first.unwrap_or(effective);
+        let end = last.unwrap_or(termination);
+
+        match self.stub {
+            Some(stub) if stub.is_initial() && first.is_none() => {
+                let (dates, exact) = regular_dates(
+                    start,
+                    end,
+                    roll_conv,
+                    period,
+                    GenerationDirection::Backward,
+                );
+                if !exact {
+                    // * dates[0] is the clipped start, dates[1] the short stub end
+                    let short = dates[1];
+                    let long = dates.get(2).copied().unwrap_or(short);
+                    first = Some(stub.choose(short, long, (short - start).num_days()));
+                }
+            }
+            Some(stub) if stub.is_final() && last.is_none() => {
+                let (dates, exact) = regular_dates(
+                    start,
+                    end,
+                    roll_conv,
+                    period,
+                    GenerationDirection::Forward,
+                );
+                if !exact {
+                    // * the last date is the clipped end, the one before starts the
+                    // * short stub
+                    let n = dates.len();
+                    let short = dates[n - 2];
+                    let long = if n >= 3 { dates[n - 3] } else { short };
+                    last = Some(stub.choose(short, long, (end - short).num_days()));
+                }
+            }
+            _ => {}
+        }
+
+        (first, last)
+    }
+}
+
+/// Roll the regular dates over $[start, end]$ in ``direction``, clipping the
+/// remainder at the far end. Also returns whether the roll landed exactly on it.
+fn regular_dates(
+    start: NaiveDate,
+    end: NaiveDate,
+    roll_conv: RollConvention,
+    period: Period,
+    direction: GenerationDirection,
+) -> (Vec<NaiveDate>, bool) {
+    match direction {
+        GenerationDirection::Forward => {
+            let mut dates = vec![start];
+            let mut tmp = start;
+            while tmp < end {
+                tmp = roll_conv.next(tmp, period);
+                dates.push(tmp.min(end));
+            }
+            (dates, tmp == end)
+        }
+        GenerationDirection::Backward => {
+            let mut dates = vec![end];
+            let mut tmp = end;
+            while tmp > start {
+                tmp = roll_conv.prev(tmp, period);
+                dates.push(tmp.max(start));
+            }
+            dates.reverse();
+            (dates, tmp == start)
+        }
+    }
+}
+
+/// Check whether the period is a number of business or calendar days.
+fn is_day_count(period: Period) -> bool {
+    matches!(period, Period::BusDays(..) | Period::Days(_))
+}
+
+pub(crate) fn invalid(msg: impl Into<String>) -> SchedulingError {
+    SchedulingError::InvalidSchedule(msg.into())
+}
+
+/// Build an [`Adjuster`] if both a calendar and convention were given.
+fn adjuster<'a>(
+    name: &str,
+    cal: Option<&'a BusinessCalendar>,
+    conv: Option<BusdayConvention>,
+) -> Result<Option<Adjuster<'a>>, SchedulingError> {
+    match (cal, conv) {
+        (Some(cal), Some(conv)) => Ok(Some(Adjuster::new(cal, conv))),
+        (None, None) => Ok(None),
+        (Some(_), None) => Err(invalid(format!(
+            "{name} calendar given without a business day convention"
+        ))),
+        (None, Some(_)) => Err(invalid(format!(
+            "{name} business day convention given without a calendar"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bizdate::{BusdayConvention, BusinessCalendar};
+    use chrono::NaiveDate;
+
+    use crate::{Offset, Period, RollConvention, SchedulingError};
+
+    use super::{
+        GenerationDirection, PaymentRelativeTo, RelativeTo, ScheduleBuilder,
+        StubConvention,
+    };
+
+    fn ymd(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn builder<'a>() -> ScheduleBuilder<'a> {
+        ScheduleBuilder::new()
+            .effective(ymd("2026-02-15"))
+            .termination(ymd("2028-02-15"))
+            .period(Period::Months(6))
+    }
+
+    fn assert_invalid(builder: ScheduleBuilder) {
+        let rslt = builder.build();
+        assert!(
+            matches!(rslt, Err(SchedulingError::InvalidSchedule(_))),
+            "{rslt:?}"
+        );
+    }
+
+    #[test]
+    fn test_unadjusted() {
+        let sch = builder().build().unwrap();
+        assert_eq!(sch.periods.len(), 4);
+        for p in sch.periods {
+            assert_eq!(p.start_adj, p.start);
+            assert_eq!(p.end_adj, p.end);
+            assert_eq!(p.pay, p.end);
+        }
+    }
+
+    #[test]
+    fn test_payment_calendar() {
+        // * 2026-08-15 is a Saturday; 2026-08-17 a holiday on the payment calendar
+        let accrual = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let payment = BusinessCalendar::new(Some(vec![ymd("2026-08-17")]), "1111100");
+        let sch = builder()
+            .accrual_calendar(&accrual)
+            .accrual_convention(BusdayConvention::Following)
+            .payment_calendar(&payment)
+            .payment_convention(BusdayConvention::Following)
+            .build()
+            .unwrap();
+        assert_eq!(sch.periods[0].end_adj, ymd("2026-08-17"));
+        assert_eq!(sch.periods[0].pay, ymd("2026-08-18"));
+    }
+
+    #[test]
+    fn test_payment_lag() {
+        // * SOFR OIS style: pay 2 business days after the adjusted period end
+        let cal = BusinessCalendar::new(Some(vec![ymd("2026-08-19")]), "1111100");
+        let sch = builder()
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::ModifiedFollowing)
+            .payment_lag(Period::BusDays(2, &cal))
+            .build()
+            .unwrap();
+        assert_eq!(sch.periods[0].end_adj, ymd("2026-08-17"));
+        assert_eq!(sch.periods[0].pay, ymd("2026-08-20"));
+        assert_eq!(sch.periods[1].end_adj, ymd("2027-02-15"));
+        assert_eq!(sch.periods[1].pay, ymd("2027-02-17"));
+
+        // * calendar day lag landing on a weekend is adjusted on the payment calendar
+        let sch = builder()
+            .payment_lag(Period::Days(5))
+            .payment_calendar(&cal)
+            .payment_convention(BusdayConvention::Following)
+            .build()
+            .unwrap();
+        assert_eq!(sch.periods[0].pay, ymd("2026-08-20"));
+        assert_eq!(sch.periods[1].pay, ymd("2027-02-22"));
+    }
+
+    #[test]
+    fn test_payment_in_advance() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = builder()
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::Following)
+            .payment_relative_to(PaymentRelativeTo::PeriodStart)
+            .build()
+            .unwrap();
+        for p in &sch.periods {
+            assert_eq!(p.pay, p.start_adj);
+        }
+        assert_eq!(sch.periods[0].pay, ymd("2026-02-16"));
+
+        let sch = builder()
+            .payment_relative_to(PaymentRelativeTo::PeriodStart)
+            .payment_lag(Period::BusDays(1, &cal))
+            .build()
+            .unwrap();
+        assert_eq!(sch.periods[2].pay, ymd("2027-02-16"));
+    }
+
+    #[test]
+    fn test_payment_lag_invalid() {
+        assert_invalid(builder().payment_lag(Period::Months(1)));
+        assert_invalid(builder().payment_lag(Period::Weeks(1)));
+    }
+
+    fn reset_builder<'a>(cal: &'a BusinessCalendar) -> ScheduleBuilder<'a> {
+        ScheduleBuilder::new()
+            .effective(ymd("2026-03-16"))
+            .termination(ymd("2027-03-16"))
+            .period(Period::Months(3))
+            .accrual_calendar(cal)
+            .accrual_convention(BusdayConvention::ModifiedFollowing)
+    }
+
+    #[test]
+    fn test_no_resets() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = reset_builder(&cal).build().unwrap();
+        for p in &sch.periods {
+            assert!(p.resets.is_empty());
+            assert_eq!(p.fixing(), None);
+        }
+    }
+
+    #[test]
+    fn test_fixing_offset() {
+        // * IBOR style: fix 2 business days before the period start on the fixing
+        // * calendar, which has a holiday the accrual calendar does not
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let fixing = BusinessCalendar::new(Some(vec![ymd("2026-06-12")]), "1111100");
+        let sch = reset_builder(&cal)
+            .fixing_offset(Offset::Before(Period::BusDays(2, &fixing)))
+            .build()
+            .unwrap();
+        let fixings: Vec<_> = sch.fixing_dates().collect();
+        let expected: Vec<_> = ["2026-03-12", "2026-06-11", "2026-09-14", "2026-12-14"]
+            .map(ymd)
+            .to_vec();
+        assert_eq!(fixings, expected);
+        for p in &sch.periods {
+            assert_eq!(p.resets.len(), 1);
+            assert_eq!(p.resets[0].start_adj, p.start_adj);
+            assert_eq!(p.resets[0].end_adj, p.end_adj);
+        }
+    }
+
+    #[test]
+    fn test_fixing_calendar() {
+        // * 2026-06-14 is a Sunday and 2026-06-15 a holiday on the fixing calendar
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let fixing = BusinessCalendar::new(Some(vec![ymd("2026-06-15")]), "1111100");
+        let sch = reset_builder(&cal)
+            .fixing_offset(Offset::Before(Period::Days(2)))
+            .fixing_calendar(&fixing)
+            .fixing_convention(BusdayConvention::Following)
+            .build()
+            .unwrap();
+        assert_eq!(sch.periods[1].fixing(), Some(ymd("2026-06-16")));
+
+        // * in arrears: fix on the adjusted period end
+        let sch = reset_builder(&cal)
+            .fixing_relative_to(RelativeTo::PeriodEnd)
+            .build()
+            .unwrap();
+        for p in &sch.periods {
+            assert_eq!(p.fixing(), Some(p.end_adj));
+        }
+    }
+
+    #[test]
+    fn test_multiple_resets() {
+        // * weekly resets on a quarterly leg; 92 days leaves a one day final reset
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = reset_builder(&cal)
+            .reset_period(Period::Weeks(1))
+            .fixing_offset(Offset::Before(Period::BusDays(1, &cal)))
+            .build()
+            .unwrap();
+        let resets = &sch.periods[0].resets;
+        assert_eq!(resets.len(), 14);
+        assert_eq!(resets[0].start, ymd("2026-03-16"));
+        assert_eq!(resets[0].end, ymd("2026-03-23"));
+        assert_eq!(resets[0].fixing, ymd("2026-03-13"));
+        assert_eq!(resets[13].start, ymd("2026-06-15"));
+        assert_eq!(resets[13].end, ymd("2026-06-16"));
+        assert_eq!(resets[13].fixing, ymd("2026-06-12"));
+        for p in &sch.periods {
+            assert_eq!(p.resets.first().unwrap().start, p.start);
+            assert_eq!(p.resets.last().unwrap().end, p.end);
+            assert!(p.resets.windows(2).all(|w| w[0].end == w[1].start));
+        }
+
+        // * monthly resets roll on the schedule's roll convention
+        let sch = ScheduleBuilder::new()
+            .effective(ymd("2026-01-31"))
+            .termination(ymd("2027-01-31"))
+            .period(Period::Months(3))
+            .roll_conv(RollConvention::Eom)
+            .reset_period(Period::Months(1))
+            .build()
+            .unwrap();
+        let ends: Vec<_> = sch.periods[0].resets.iter().map(|r| r.end).collect();
+        assert_eq!(ends, ["2026-02-28", "2026-03-31", "2026-04-30"].map(ymd));
+    }
+
+    #[test]
+    fn test_fixing_invalid() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        assert_invalid(
+            reset_builder(&cal).fixing_offset(Offset::Before(Period::Months(1))),
+        );
+        assert_invalid(reset_builder(&cal).reset_period(Period::Days(0)));
+        assert_invalid(reset_builder(&cal).fixing_calendar(&cal));
+
+        // * the fixing calendar must be the one the offset counts business days on
+        let fixing = BusinessCalendar::new(Some(vec![ymd("2026-06-15")]), "1111100");
+        assert_invalid(
+            reset_builder(&cal)
+                .fixing_offset(Offset::Before(Period::BusDays(2, &cal)))
+                .fixing_calendar(&fixing)
+                .fixing_convention(BusdayConvention::Following),
+        );
+        let rslt = reset_builder(&cal)
+            .fixing_offset(Offset::Before(Period::BusDays(2, &fixing)))
+            .fixing_calendar(&fixing)
+            .fixing_convention(BusdayConvention::Following)
+            .build();
+        assert!(rslt.is_ok());
+    }
+
+    fn gilt_builder<'a>(cal: &'a BusinessCalendar) -> ScheduleBuilder<'a> {
+        ScheduleBuilder::new()
+            .effective(ymd("2025-12-07"))
+            .termination(ymd("2027-12-07"))
+            .period(Period::Months(6))
+            .payment_calendar(cal)
+            .payment_convention(BusdayConvention::Following)
+    }
+
+    #[test]
+    fn test_ex_coupon() {
+        // * gilts go ex-dividend 7 business days before payment; 2026-06-07 is a
+        // * Sunday paid on 2026-06-08
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = gilt_builder(&cal).build().unwrap();
+        assert!(sch.periods.iter().all(|p| p.record.is_none()));
+
+        let sch = gilt_builder(&cal)
+            .ex_coupon(Period::BusDays(7, &cal))
+            .build()
+            .unwrap();
+        let records: Vec<_> = sch.record_dates().collect();
+        let expected: Vec<_> = ["2026-05-28", "2026-11-26", "2027-05-27", "2027-11-26"]
+            .into_iter()
+            .map(ymd)
+            .collect();
+        assert_eq!(records, expected);
+        assert!(!sch.periods[1].is_ex_coupon(ymd("2026-11-25")));
+        assert!(sch.periods[1].is_ex_coupon(ymd("2026-11-26")));
+        assert!(sch.periods[1].is_ex_coupon(ymd("2026-12-04")));
+        assert!(!sch.periods[1].is_ex_coupon(ymd("2026-12-07")));
+        assert!(sch.is_ex_coupon(ymd("2027-05-27")));
+        assert!(!sch.is_ex_coupon(ymd("2027-05-26")));
+    }
+
+    #[test]
+    fn test_ex_coupon_calendar() {
+        // * Australian style: 8 calendar days before payment, 2026-11-29 is a Sunday
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let sch = gilt_builder(&cal)
+            .ex_coupon(Period::Days(8))
+            .build()
+            .unwrap();
+        assert_eq!(sch.periods[1].record_date(), Some(ymd("2026-11-29")));
+
+        let sch = gilt_builder(&cal)
+            .ex_coupon(Period::Days(8))
+            .ex_coupon_calendar(&cal)
+            .ex_coupon_convention(BusdayConvention::Preceding)
+            .build()
+            .unwrap();
+        assert_eq!(sch.periods[1].record_date(), Some(ymd("2026-11-27")));
+    }
+
+    #[test]
+    fn test_ex_coupon_invalid() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        assert_invalid(gilt_builder(&cal).ex_coupon(Period::Months(1)));
+        assert_invalid(gilt_builder(&cal).ex_coupon_calendar(&cal));
+        assert_invalid(
+            gilt_builder(&cal)
+                .ex_coupon_calendar(&cal)
+                .ex_coupon_convention(BusdayConvention::Preceding),
+        );
+    }
+
+    #[test]
+    fn test_roll_conv() {
+        let sch = ScheduleBuilder::new()
+            .effective(ymd("2026-02-28"))
+            .termination(ymd("2027-02-28"))
+            .period(Period::Months(3))
+            .roll_conv(RollConvention::Eom)
+            .build()
+            .unwrap();
+        let ends: Vec<_> = sch.periods.iter().map(|p| p.end).collect();
+        let expected = ["2026-05-31", "2026-08-31", "2026-11-30", "2027-02-28"];
+        assert_eq!(ends, expected.map(ymd));
+        assert_eq!(sch.roll_conv(), RollConvention::Eom);
+
+        // * weekly periods are never rolled to a day of month
+        let sch = ScheduleBuilder::new()
+            .effective(ymd("2026-01-30"))
+            .termination(ymd("2026-02-27"))
+            .period(Period::Weeks(1))
+            .build()
+            .unwrap();
+        assert_eq!(sch.roll_conv(), RollConvention::None);
+        assert_eq!(sch.periods[0].end, ymd("2026-02-06"));
+    }
+
+    fn period_dates(builder: ScheduleBuilder) -> Vec<(NaiveDate, NaiveDate)> {
+        let sch = builder.build().unwrap();
+        sch.periods
+            .iter()
+            .map(|p| (p.start_adj, p.end_adj))
+            .collect()
+    }
+
+    fn expected_dates(dates: &[&str]) -> Vec<(NaiveDate, NaiveDate)> {
+        dates.windows(2).map(|w| (ymd(w[0]), ymd(w[1]))).collect()
+    }
+
+    // * expected dates match QuantLib's Schedule with DateGeneration::Backward
+
+    #[test]
+    fn test_backward_regular() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let builder = ScheduleBuilder::new()
+            .effective(ymd("2026-01-20"))
+            .termination(ymd("2028-01-20"))
+            .period(Period::Months(6))
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::ModifiedFollowing)
+            .direction(GenerationDirection::Backward);
+        let expected = expected_dates(&[
+            "2026-01-20",
+            "2026-07-20",
+            "2027-01-20",
+            "2027-07-20",
+            "2028-01-20",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+    }
+
+    #[test]
+    fn test_backward_short_initial() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let builder = ScheduleBuilder::new()
+            .effective(ymd("2026-03-10"))
+            .termination(ymd("2028-09-15"))
+            .period(Period::Months(6))
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::ModifiedFollowing)
+            .direction(GenerationDirection::Backward);
+        let expected = expected_dates(&[
+            "2026-03-10",
+            "2026-03-16",
+            "2026-09-15",
+            "2027-03-15",
+            "2027-09-15",
+            "2028-03-15",
+            "2028-09-15",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+
+        // * the same schedule rolled forward puts the remainder at the back
+        let builder = builder.direction(GenerationDirection::Forward);
+        let sch = builder.build().unwrap();
+        assert_eq!(sch.roll_conv(), RollConvention::DayOfMonth(10));
+        let expected = expected_dates(&[
+            "2026-03-10",
+            "2026-09-10",
+            "2027-03-10",
+            "2027-09-10",
+            "2028-03-10",
+            "2028-09-11",
+            "2028-09-15",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+    }
+
+    #[test]
+    fn test_backward_eom() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        let builder = ScheduleBuilder::new()
+            .effective(ymd("2026-01-15"))
+            .termination(ymd("2027-06-30"))
+            .period(Period::Months(3))
+            .roll_conv(RollConvention::Eom)
+            .accrual_calendar(&cal)
+            .accrual_convention(BusdayConvention::ModifiedFollowing)
+            .direction(GenerationDirection::Backward);
+        let expected = expected_dates(&[
+            "2026-01-15",
+            "2026-03-31",
+            "2026-06-30",
+            "2026-09-30",
+            "2026-12-31",
+            "2027-03-31",
+            "2027-06-30",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+    }
+
+    #[test]
+    fn test_backward_roll_day() {
+        // * rolling back from the 31st does not drift after short months
+        let builder = ScheduleBuilder::new()
+            .effective(ymd("2025-08-31"))
+            .termination(ymd("2027-08-31"))
+            .period(Period::Months(6))
+            .direction(GenerationDirection::Backward);
+        let expected = expected_dates(&[
+            "2025-08-31",
+            "2026-02-28",
+            "2026-08-31",
+            "2027-02-28",
+            "2027-08-31",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+    }
+
+    #[test]
+    fn test_backward_back_stub() {
+        let builder = ScheduleBuilder::new()
+            .effective(ymd("2026-01-10"))
+            .last_regular(ymd("2027-03-15"))
+            .termination(ymd("2027-05-01"))
+            .period(Period::Months(6))
+            .direction(GenerationDirection::Backward);
+        let expected = expected_dates(&[
+            "2026-01-10",
+            "2026-03-15",
+            "2026-09-15",
+            "2027-03-15",
+            "2027-05-01",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+    }
+
+    fn stub_builder<'a>(effective: &str, termination: &str) -> ScheduleBuilder<'a> {
+        ScheduleBuilder::new()
+            .effective(ymd(effective))
+            .termination(ymd(termination))
+            .period(Period::Months(6))
+    }
+
+    #[test]
+    fn test_stub_initial() {
+        let builder = stub_builder("2026-03-10", "2028-03-15");
+        let regular = ["2026-09-15", "2027-03-15", "2027-09-15", "2028-03-15"];
+
+        let short = builder.stub(StubConvention::ShortInitial);
+        let expected = [&["2026-03-10", "2026-03-15"], &regular[..]].concat();
+        assert_eq!(period_dates(short), expected_dates(&expected));
+
+        let long = builder.stub(StubConvention::LongInitial);
+        let expected = [&["2026-03-10"], &regular[..]].concat();
+        assert_eq!(period_dates(long), expected_dates(&expected));
+
+        // * a 5 day stub is merged into the next period
+        let smart = builder.stub(StubConvention::SmartInitial);
+        assert_eq!(period_dates(smart), expected_dates(&expected));
+
+        // * but a 64 day stub is not
+        let smart =
+            stub_builder("2026-01-10", "2028-03-15").stub(StubConvention::SmartInitial);
+        let expected = [&["2026-01-10", "2026-03-15"], &regular[..]].concat();
+        assert_eq!(period_dates(smart), expected_dates(&expected));
+    }
+
+    #[test]
+    fn test_stub_final() {
+        let builder = stub_builder("2026-03-15", "2028-03-20");
+        let regular = ["2026-03-15", "2026-09-15", "2027-03-15", "2027-09-15"];
+
+        let short = builder.stub(StubConvention::ShortFinal);
+        let expected = [&regular[..], &["2028-03-15", "2028-03-20"]].concat();
+        assert_eq!(period_dates(short), expected_dates(&expected));
+
+        let long = builder.stub(StubConvention::LongFinal);
+        let expected = [&regular[..], &["2028-03-20"]].concat();
+        assert_eq!(period_dates(long), expected_dates(&expected));
+
+        let smart = builder.stub(StubConvention::SmartFinal);
+        assert_eq!(period_dates(smart), expected_dates(&expected));
+
+        let smart =
+            stub_builder("2026-03-15", "2028-09-10").stub(StubConvention::SmartFinal);
+        let expected = [&regular[..], &["2028-03-15", "2028-09-10"]].concat();
+        assert_eq!(period_dates(smart), expected_dates(&expected));
+    }
+
+    #[test]
+    fn test_stub_no_remainder() {
+        let builder = stub_builder("2026-03-15", "2028-03-15");
+        let expected = expected_dates(&[
+            "2026-03-15",
+            "2026-09-15",
+            "2027-03-15",
+            "2027-09-15",
+            "2028-03-15",
+        ]);
+        for stub in [
+            StubConvention::None,
+            StubConvention::ShortInitial,
+            StubConvention::LongInitial,
+            StubConvention::ShortFinal,
+            StubConvention::LongFinal,
+        ] {
+            assert_eq!(period_dates(builder.stub(stub)), expected, "{stub:?}");
+        }
+    }
+
+    #[test]
+    fn test_stub_single_period() {
+        // * less than one regular period leaves a single stub
+        let builder = stub_builder("2026-01-15", "2026-04-01");
+        let expected = expected_dates(&["2026-01-15", "2026-04-01"]);
+        for stub in [StubConvention::LongInitial, StubConvention::ShortFinal] {
+            assert_eq!(period_dates(builder.stub(stub)), expected, "{stub:?}");
+        }
+    }
+
+    #[test]
+    fn test_stub_explicit_dates() {
+        // * explicit front stub with an inferred back stub
+        let builder = stub_builder("2026-01-10", "2027-05-01")
+            .first_regular(ymd("2026-03-15"))
+            .stub(StubConvention::ShortFinal);
+        let expected = expected_dates(&[
+            "2026-01-10",
+            "2026-03-15",
+            "2026-09-15",
+            "2027-03-15",
+            "2027-05-01",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+    }
+
+    #[test]
+    fn test_stub_invalid() {
+        // * no stub allowed but 2 years and 5 days is not a whole number of periods
+        assert_invalid(
+            stub_builder("2026-03-10", "2028-03-15").stub(StubConvention::None),
+        );
+        // * explicit stub leaves a remainder the convention cannot absorb
+        assert_invalid(
+            stub_builder("2026-03-10", "2028-03-15")
+                .last_regular(ymd("2028-01-01"))
+                .stub(StubConvention::ShortFinal),
+        );
+        // * the convention implies backward generation
+        assert_invalid(
+            stub_builder("2026-03-10", "2028-03-15")
+                .stub(StubConvention::ShortInitial)
+                .direction(GenerationDirection::Forward),
+        );
+    }
+
+    /// Check the periods are contiguous and cover exactly [effective, termination].
+    fn assert_contiguous(builder: ScheduleBuilder, effective: &str, termination: &str) {
+        let sch = builder.build().unwrap();
+        assert_eq!(sch.periods.first().unwrap().start, ymd(effective));
+        assert_eq!(sch.periods.last().unwrap().end, ymd(termination));
+        for w in sch.periods.windows(2) {
+            assert!(w[0].start < w[0].end, "{:?}", w[0]);
+            assert_eq!(w[0].end, w[1].start);
+        }
+    }
+
+    #[test]
+    fn test_forward_automatic_stub() {
+        // * the last period used to end on 2028-08-15, past the termination date
+        let builder = stub_builder("2026-02-15", "2028-06-15");
+        let expected = expected_dates(&[
+            "2026-02-15",
+            "2026-08-15",
+            "2027-02-15",
+            "2027-08-15",
+            "2028-02-15",
+            "2028-06-15",
+        ]);
+        assert_eq!(period_dates(builder), expected);
+    }
+
+    #[test]
+    fn test_contiguous() {
+        let cases = [
+            ("2026-02-15", "2028-02-15"),
+            ("2026-02-15", "2028-06-15"),
+            ("2026-03-10", "2028-09-15"),
+            ("2025-08-31", "2027-07-30"),
+            ("2026-01-15", "2026-04-01"),
+        ];
+        let directions = [GenerationDirection::Forward, GenerationDirection::Backward];
+        for (effective, termination) in cases {
+            for direction in directions {
+                let builder = stub_builder(effective, termination).direction(direction);
+                assert_contiguous(builder, effective, termination);
+                let builder = builder
+                    .period(Period::Months(1))
+                    .roll_conv(RollConvention::Eom);
+                assert_contiguous(builder, effective, termination);
+            }
+        }
+    }
+
+    #[test]
+    fn test_overlapping_back_stub() {
+        // * regular periods from the effective date run past the last regular date
+        let builder =
+            stub_builder("2026-02-15", "2028-06-15").last_regular(ymd("2027-11-15"));
+        assert_invalid(builder);
+    }
+
+    #[test]
+    fn test_overlapping_front_stub() {
+        // * regular periods rolled back from the termination date run past the first
+        // * regular date
+        let builder = stub_builder("2026-02-15", "2028-02-15")
+            .first_regular(ymd("2026-04-01"))
+            .direction(GenerationDirection::Backward);
+        assert_invalid(builder);
+
+        // * a front stub rolled forward may leave an automatic back stub
+        let builder =
+            stub_builder("2026-02-15", "2028-02-15").first_regular(ymd("2026-04-01"));
+        assert_contiguous(builder, "2026-02-15", "2028-02-15");
+        assert_eq!(builder.build().unwrap().periods.len(), 5);
+    }
+
+    #[test]
+    fn test_missing_inputs() {
+        assert_invalid(ScheduleBuilder::new());
+        assert_invalid(builder().period(Period::Months(0)));
+        let rslt = ScheduleBuilder::new()
+            .effective(ymd("2026-02-15"))
+            .period(Period::Months(6))
+            .build();
+        assert_eq!(
+            rslt.unwrap_err(),
+            SchedulingError::InvalidSchedule("missing termination date".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        assert_invalid(builder().termination(ymd("2026-02-15")));
+        assert_invalid(builder().first_regular(ymd("2026-02-15")));
+        assert_invalid(builder().first_regular(ymd("2028-03-15")));
+        assert_invalid(builder().last_regular(ymd("2028-02-15")));
+        assert_invalid(builder().last_regular(ymd("2026-01-15")));
+        assert_invalid(
+            builder()
+                .first_regular(ymd("2027-02-15"))
+                .last_regular(ymd("2026-08-15")),
+        );
+    }
+
+    #[test]
+    fn test_invalid_adjustment() {
+        let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");
+        assert_invalid(builder().accrual_calendar(&cal));
+        assert_invalid(builder().payment_convention(BusdayConvention::Following));
+        assert_invalid(
+            builder()
+                .period(Period::Days(7))
+                .roll_conv(RollConvention::Eom),
+        );
+    }
+}
@@ -2,14 +2,29 @@ use bizdate::{BusdayConvention, BusinessCalendar};
 use chrono::NaiveDate;
 
 use crate::period::Period;
+use crate::reset_period::ResetPeriod;
 use crate::roll_conv::{Roll, RollConvention};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Which pair of period dates accrual is measured between.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AccrualDates {
+    #[default]
+    Unadjusted,
+    Adjusted,
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct SchedulePeriod {
     pub(crate) start: NaiveDate,
     pub(crate) end: NaiveDate,
     pub(crate) start_adj: NaiveDate,
     pub(crate) end_adj: NaiveDate,
+    pub(crate) pay: NaiveDate,
+    pub(crate) resets: Vec<ResetPeriod>,
+    pub(crate) record: Option<NaiveDate>,
+    pub(crate) reference: Option<(NaiveDate, NaiveDate)>,
+    pub(crate) notional: Option<f64>,
+    pub(crate) principal: f64,
 }
 
 impl SchedulePeriod {
@@ -24,6 +39,12 @@ impl SchedulePeriod {
             end,
             start_adj,
             end_adj,
+            pay: end_adj,
+            resets: Vec::new(),
+            record: None,
+            reference: None,
+            notional: None,
+            principal: 0.0,
         }
     }
 
@@ -40,6 +61,12 @@ impl SchedulePeriod {
             end,
             start_adj,
             end_adj,
+            pay: end_adj,
+            resets: Vec::new(),
+            record: None,
+            reference: None,
+            notional: None,
+            principal: 0.0,
         }
     }
 
@@ -58,6 +85,96 @@ impl SchedulePeriod {
             end,
             start_adj,
             end_adj,
+            pay: end_adj,
+            resets: Vec::new(),
+            record: None,
+            reference: None,
+            notional: None,
+            principal: 0.0,
+        }
+    }
+
+    /// Get the unadjusted start date.
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Get the unadjusted end date.
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Get the business day-adjusted start date.
+    pub fn start_adj(&self) -> NaiveDate {
+        self.start_adj
+    }
+
+    /// Get the business day-adjusted end date.
+    pub fn end_adj(&self) -> NaiveDate {
+        self.end_adj
+    }
+
+    /// Get the payment date.
+    pub fn pay(&self) -> NaiveDate {
+        self.pay
+    }
+
+    /// Get the resets of the period, empty unless the schedule was built with fixings.
+    pub fn resets(&self) -> &[ResetPeriod] {
+        &self.resets
+    }
+
+    /// Get the fixing date of the first reset in the period, if any.
+    pub fn fixing(&self) -> Option<NaiveDate> {
+        self.resets.first().map(|r| r.fixing)
+    }
+
+    /// Get the record date, from which the period's coupon goes ex, if the schedule
+    /// was built with an ex-coupon offset.
+    pub fn record_date(&self) -> Option<NaiveDate> {
+        self.record
+    }
+
+    /// Check if settlement on ``dt`` falls in the ex-coupon period, i.e.
+    /// $dt \in [record, pay)$, so that the buyer does not receive the coupon.
+    pub fn is_ex_coupon(&self, dt: NaiveDate) -> bool {
+        self.record
+            .is_some_and(|record| dt >= record && dt < self.pay)
+    }
+
+    /// Get the unadjusted start and end of the period before it was truncated (see
+    /// [`Schedule::truncate`](crate::Schedule::truncate)), which Act/Act ICMA accrues
+    /// over; the period's own dates unless truncated.
+    pub fn reference_period(&self) -> (NaiveDate, NaiveDate) {
+        self.reference.unwrap_or((self.start, self.end))
+    }
+
+    /// Get the notional outstanding over the period, if the schedule has been
+    /// amortized.
+    pub fn notional(&self) -> Option<f64> {
+        self.notional
+    }
+
+    /// Get the principal repaid at the end of the period.
+    pub fn principal(&self) -> f64 {
+        self.principal
+    }
+
+    /// Get the number of calendar days between the unadjusted start and end.
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days()
+    }
+
+    /// Get the number of calendar days between the adjusted start and end.
+    pub fn adjusted_days(&self) -> i64 {
+        (self.end_adj - self.start_adj).num_days()
+    }
+
+    /// Get the accrual start and end dates of the period.
+    pub fn accrual_dates(&self, dates: AccrualDates) -> (NaiveDate, NaiveDate) {
+        match dates {
+            AccrualDates::Unadjusted => (self.start, self.end),
+            AccrualDates::Adjusted => (self.start_adj, self.end_adj),
         }
     }
 
@@ -105,6 +222,12 @@ mod tests {
         end: NaiveDate::from_ymd_opt(2026, 8, 31).unwrap(),
         start_adj: NaiveDate::from_ymd_opt(2026, 2, 27).unwrap(),
         end_adj: NaiveDate::from_ymd_opt(2026, 8, 31).unwrap(),
+        pay: NaiveDate::from_ymd_opt(2026, 8, 31).unwrap(),
+        resets: Vec::new(),
+        record: None,
+        reference: None,
+        notional: None,
+        principal: 0.0,
     };
 
     static SHORT: &SchedulePeriod = &SchedulePeriod {
@@ -112,6 +235,12 @@ mod tests {
         end: NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
         start_adj: NaiveDate::from_ymd_opt(2026, 2, 27).unwrap(),
         end_adj: NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
+        pay: NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
+        resets: Vec::new(),
+        record: None,
+        reference: None,
+        notional: None,
+        principal: 0.0,
     };
 
     static LONG: &SchedulePeriod = &SchedulePeriod {
@@ -119,6 +248,12 @@ mod tests {
         end: NaiveDate::from_ymd_opt(2026, 10, 30).unwrap(),
         start_adj: NaiveDate::from_ymd_opt(2026, 2, 27).unwrap(),
         end_adj: NaiveDate::from_ymd_opt(2026, 10, 30).unwrap(),
+        pay: NaiveDate::from_ymd_opt(2026, 10, 30).unwrap(),
+        resets: Vec::new(),
+        record: None,
+        reference: None,
+        notional: None,
+        principal: 0.0,
     };
 
     #[test]
@@ -151,6 +286,34 @@ mod tests {
         assert!(!LONG.is_short_stub(roll_conv, period));
     }
 
+    #[test]
+    fn test_getters() {
+        assert_eq!(REG.start(), NaiveDate::from_ymd_opt(2026, 2, 28).unwrap());
+        assert_eq!(REG.end(), NaiveDate::from_ymd_opt(2026, 8, 31).unwrap());
+        assert_eq!(
+            REG.start_adj(),
+            NaiveDate::from_ymd_opt(2026, 2, 27).unwrap()
+        );
+        assert_eq!(REG.end_adj(), NaiveDate::from_ymd_opt(2026, 8, 31).unwrap());
+        assert_eq!(REG.days(), 184);
+        assert_eq!(REG.adjusted_days(), 185);
+        assert_eq!(REG.record_date(), None);
+        assert_eq!(REG.reference_period(), (REG.start, REG.end));
+    }
+
+    #[test]
+    fn test_ex_coupon() {
+        let mut period = REG.clone();
+        assert!(!period.is_ex_coupon(NaiveDate::from_ymd_opt(2026, 8, 30).unwrap()));
+
+        period.record = NaiveDate::from_ymd_opt(2026, 8, 21);
+        let ex: Vec<_> = [20, 21, 28, 31]
+            .into_iter()
+            .map(|d| period.is_ex_coupon(NaiveDate::from_ymd_opt(2026, 8, d).unwrap()))
+            .collect();
+        assert_eq!(ex, [false, true, true, false]);
+    }
+
     #[test]
     fn test_new_adjust() {
         let cal = BusinessCalendar::new(None::<Vec<NaiveDate>>, "1111100");